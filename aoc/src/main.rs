@@ -0,0 +1,349 @@
+use clap::{
+    crate_description, crate_name, crate_version, value_parser, Arg, ArgMatches, ColorChoice,
+    Command,
+};
+use general::answers::{self, Manifest};
+use general::generate::{generate, Generate};
+use general::input::{day_dirs, find_input, input_arg, input_args, Input, ACTUAL, EXAMPLE};
+use general::output::{Emitter, Format, Record};
+use general::params::{param_flags, param_overrides, split_param};
+use general::{
+    exit_code, output_arg, param_arg, part_arg, reset_sigpipe, solve_file, Answers, Param, Part,
+    PuzzleError,
+};
+use std::error::Error;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod summary;
+
+// solves the selected parts of an input with "name=value" parameter overrides
+type Solver = fn(Option<&PathBuf>, &[String], Part) -> Result<Answers, PuzzleError>;
+
+// generates an input from a seed and "name=value" size overrides
+type Generator = fn(u64, &[String]) -> Result<Vec<String>, PuzzleError>;
+
+// a registered puzzle solution
+pub struct Day {
+    day: u8,
+    title: &'static str,
+    params: &'static [Param],
+    solver: Solver,
+    generator: Generator,
+}
+
+fn day<S: Generate>() -> Day {
+    Day {
+        day: S::DAY,
+        title: S::TITLE,
+        params: S::PARAMS,
+        solver: solve_file::<S>,
+        generator: generate::<S>,
+    }
+}
+
+fn days() -> Vec<Day> {
+    vec![
+        day::<day_01::Day01>(),
+        day::<day_02::Day02>(),
+        day::<day_03::Day03>(),
+        day::<day_04::Day04>(),
+        day::<day_05::Day05>(),
+        day::<day_06::Day06>(),
+        day::<day_07::Day07>(),
+        day::<day_08::Day08>(),
+        day::<day_09::Day09>(),
+        day::<day_10::Day10>(),
+        day::<day_11::Day11>(),
+        day::<day_12::Day12>(),
+        day::<day_13::Day13>(),
+        day::<day_14::Day14>(),
+        day::<day_15::Day15>(),
+        day::<day_16::Day16>(),
+        day::<day_17::Day17>(),
+        day::<day_18::Day18>(),
+        day::<day_19::Day19>(),
+        day::<day_20::Day20>(),
+        day::<day_21::Day21>(),
+        day::<day_22::Day22>(),
+        day::<day_23::Day23>(),
+        day::<day_24::Day24>(),
+        day::<day_25::Day25>(),
+    ]
+}
+
+// the parameters of every day, once each by name for their --NAME VALUE options
+fn params(days: &[Day]) -> Vec<&'static Param> {
+    let mut params: Vec<&'static Param> = vec![];
+    for p in days.iter().flat_map(|d| d.params) {
+        if params.iter().all(|q| q.name != p.name) {
+            params.push(p);
+        }
+    }
+    params
+}
+
+// clap-4 arg parser for the `run` subcommand
+fn get_args() -> ArgMatches {
+    Command::new(crate_name!())
+        .version(crate_version!())
+        .about(crate_description!())
+        .color(ColorChoice::Auto)
+        .max_term_width(100)
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("run")
+                .about("Run the solution for a day, or all days")
+                .arg(
+                    Arg::new("DAY")
+                        .help("Day number (1-25) or 'all'")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("FILE")
+                        .short('i')
+                        .help("File to read, use '-' for standard input, for 'all' the file of each day's directory [default for 'all': input-actual]")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .args(input_args())
+                .arg(
+                    Arg::new("time")
+                        .short('t')
+                        .help("Show runtime")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(part_arg())
+                .arg(output_arg())
+                .arg(param_arg())
+                .args(param_flags(params(&days())))
+                .arg(
+                    Arg::new("check")
+                        .long("check")
+                        .help("Check the answers against each day's answers.toml")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("summary")
+                .about("Solve every input of every day concurrently and summarize the results")
+                .arg(
+                    Arg::new("days")
+                        .short('d')
+                        .long("days")
+                        .value_name("DAYS")
+                        .help("Only these days, ex. 1-5,10 [default: all]")
+                        .value_parser(summary::parse_days),
+                )
+                .arg(
+                    Arg::new("input")
+                        .short('i')
+                        .value_name("NAME")
+                        .help("Only inputs with this file name, ex. input-actual (repeatable) [default: all]")
+                        .action(clap::ArgAction::Append),
+                )
+                .arg(
+                    Arg::new("sort")
+                        .long("sort")
+                        .help("Sort the table by day or by time (slowest first)")
+                        .value_parser(["day", "time"])
+                        .default_value("day"),
+                )
+                .arg(
+                    Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .value_name("N")
+                        .help("Number of worker threads [default: available parallelism]")
+                        .value_parser(value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("gen")
+                .about("Generate a random input for a day, the same seed and sizes give the same input")
+                .arg(
+                    Arg::new("DAY")
+                        .help("Day number (1-25)")
+                        .value_parser(value_parser!(u8))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("seed")
+                        .short('s')
+                        .long("seed")
+                        .value_name("N")
+                        .help("Seed of the random generator")
+                        .value_parser(value_parser!(u64))
+                        .default_value("0"),
+                )
+                .arg(
+                    Arg::new("size")
+                        .long("size")
+                        .value_name("NAME=VALUE")
+                        .help("Set a size of the input, ex. elves=1000 (repeatable)")
+                        .value_parser(|s: &str| split_param(s).map(|_| s.to_string()))
+                        .action(clap::ArgAction::Append),
+                ),
+        )
+        .get_matches()
+}
+
+fn run(args: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let days = days();
+
+    let selected = match args.get_one::<String>("DAY").map(|s| s.as_str()) {
+        Some("all") => days.iter().collect::<Vec<_>>(),
+        Some(n) => {
+            let n = n.parse::<u8>()?;
+            match days.iter().find(|d| d.day == n) {
+                Some(d) => vec![d],
+                None => return Err(Box::from(format!("No solution for day {n}"))),
+            }
+        }
+        None => return Err(Box::from("missing DAY")),
+    };
+
+    // parameters are declared per day so they only make sense for one
+    let overrides = param_overrides(args, params(&days));
+    if !overrides.is_empty() && selected.len() > 1 {
+        return Err(Box::from("--param needs a single day"));
+    }
+
+    // a single day reads standard input by default like the day binaries
+    // while "all" defaults to each day's actual puzzle input, and -i FILE
+    // names a file of each day's directory
+    let defaulted = input_arg(args).is_none();
+    let file = match (input_arg(args), selected.as_slice()) {
+        (Some(input), [_]) => input,
+        (Some(input), _) if input.path().as_os_str() == "-" => {
+            return Err(Box::from(
+                "standard input can only be read for a single day",
+            ))
+        }
+        (Some(input), _) => input.per_day(),
+        (None, [d]) => Input::File(find_input(d.day, None)?),
+        (None, _) => Input::Day(ACTUAL.into()),
+    };
+
+    let format = args
+        .get_one::<Format>("output")
+        .copied()
+        .unwrap_or_default();
+    let part = args.get_one::<Part>("part").copied().unwrap_or_default();
+    let mut emitter = Emitter::new(io::stdout().lock(), format)
+        .show_day(true)
+        .show_timings(args.get_flag("time"));
+
+    let timer = std::time::Instant::now();
+    let mut failed = vec![];
+    let mut skipped = vec![];
+    for d in &selected {
+        let input = match find_input(d.day, Some(&file)) {
+            Ok(input) => input,
+            // a day without a puzzle input still has the inputs its answers cover
+            Err(_) if selected.len() > 1 => match fallback_input(d.day).filter(|_| defaulted) {
+                Some(input) => {
+                    eprintln!("Day {:02}: no {ACTUAL}, running {}", d.day, input.display());
+                    input
+                }
+                None => {
+                    eprintln!("Day {:02}: skipped, no {}", d.day, file.path().display());
+                    skipped.push(d.day);
+                    continue;
+                }
+            },
+            Err(err) => return Err(err.into()),
+        };
+
+        let answers = (d.solver)(Some(&input), &overrides, part)?;
+        emitter.emit(&Record {
+            day: d.day,
+            title: d.title,
+            input: Some(&input)
+                .filter(|f| f.as_os_str() != "-")
+                .map(|f| f.as_path()),
+            answers: &answers,
+        })?;
+
+        // keep going after a wrong answer so every day is checked
+        if args.get_flag("check") {
+            let checked = Manifest::for_input(&input).and_then(|(_, expected)| {
+                answers::check(&input.display().to_string(), &answers, &expected)
+            });
+            if let Err(err) = checked {
+                eprintln!("Day {:02}: {err}", d.day);
+                failed.push(d.day);
+            }
+        }
+    }
+
+    if args.get_flag("time") && selected.len() > 1 && format == Format::Text {
+        writeln!(io::stdout(), "Total Runtime: {:?}", timer.elapsed())?;
+    }
+
+    // a day without its input is as unchecked as a wrong answer
+    let check = args.get_flag("check");
+    match (failed.is_empty(), skipped.is_empty() || !check) {
+        (true, true) => Ok(()),
+        (false, true) => Err(Box::from(format!("Check failed for day(s) {failed:?}"))),
+        (true, false) => Err(Box::from(format!("Check skipped day(s) {skipped:?}"))),
+        (false, false) => Err(Box::from(format!(
+            "Check failed for day(s) {failed:?} and skipped day(s) {skipped:?}"
+        ))),
+    }
+}
+
+// The first input besides the example that a day's answers.toml covers,
+// for the days without an actual puzzle input
+fn fallback_input(day: u8) -> Option<PathBuf> {
+    day_dirs(day).into_iter().find_map(|dir| {
+        let manifest = Manifest::load(&dir.join(answers::MANIFEST)).ok()?;
+        let covered = manifest
+            .inputs()
+            .map(|(name, _)| dir.join(name))
+            .find(|path| path.file_name().is_some_and(|n| n != EXAMPLE) && path.is_file());
+        covered
+    })
+}
+
+// writes a generated input to stdout
+fn gen(args: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let n = args.get_one::<u8>("DAY").copied().ok_or("missing DAY")?;
+    let d = days()
+        .into_iter()
+        .find(|d| d.day == n)
+        .ok_or_else(|| format!("No solution for day {n}"))?;
+    let seed = args.get_one::<u64>("seed").copied().unwrap_or_default();
+    let sizes = args
+        .get_many::<String>("size")
+        .map(|s| s.cloned().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    for line in (d.generator)(seed, &sizes)? {
+        writeln!(out, "{line}")?;
+    }
+    Ok(out.flush()?)
+}
+
+fn main() -> ExitCode {
+    // parse command line arguments
+    let args = get_args();
+
+    let result = match args.subcommand() {
+        Some(("run", sub_args)) => reset_sigpipe().and_then(|_| run(sub_args)),
+        Some(("summary", sub_args)) => {
+            reset_sigpipe().and_then(|_| summary::summary(sub_args, &days()))
+        }
+        Some(("gen", sub_args)) => reset_sigpipe().and_then(|_| gen(sub_args)),
+        _ => unreachable!("subcommand_required"),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            exit_code(err.as_ref())
+        }
+    }
+}
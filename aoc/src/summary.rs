@@ -0,0 +1,294 @@
+use crate::Day;
+use clap::ArgMatches;
+use general::answers::{self, Manifest};
+use general::input::day_dir;
+use general::{Answers, Part};
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// how a day did on one input
+enum Status {
+    // every known answer matched
+    Pass,
+    // a known answer differs, holds the diff
+    Fail(String),
+    // the solver returned an error or panicked
+    Error(String),
+    // there are no known answers for the input
+    Unchecked,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail(_) => "FAIL",
+            Self::Error(_) => "ERROR",
+            Self::Unchecked => "-",
+        }
+    }
+}
+
+struct Outcome<'a> {
+    day: &'a Day,
+    input: PathBuf,
+    answers: Option<Answers>,
+    status: Status,
+}
+
+impl Outcome<'_> {
+    fn runtime(&self) -> Option<Duration> {
+        self.answers.as_ref().map(|a| a.timings.total())
+    }
+}
+
+// Parses a list of days and ranges, ex. "1-5,10" => [1, 2, 3, 4, 5, 10]
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+    for part in spec.split(',').map(|s| s.trim()) {
+        let day = |s: &str| {
+            s.trim()
+                .parse::<u8>()
+                .map_err(|e| format!("invalid day {s:?}: {e}"))
+        };
+        match part.split_once('-') {
+            Some((lo, hi)) => days.extend(day(lo)?..=day(hi)?),
+            None => days.push(day(part)?),
+        }
+    }
+    Ok(days)
+}
+
+// the input files of a day, examples first, ex. input-example, input-example2, input-actual
+fn day_inputs(day: u8) -> Vec<PathBuf> {
+    let Some(dir) = day_dir(day) else {
+        return vec![];
+    };
+    let mut inputs = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| {
+            p.is_file()
+                && p.file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with("input"))
+        })
+        .collect::<Vec<_>>();
+    inputs.sort_by_key(|p| (p.ends_with("input-actual"), p.clone()));
+    inputs
+}
+
+// solves an input with the params and checks it against the answers in the answers.toml beside it
+fn solve(day: &Day, input: PathBuf) -> Outcome<'_> {
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        (day.solver)(Some(&input), &[], Part::All)
+    }));
+    let (answers, status) = match solved {
+        Ok(Ok(answers)) => {
+            let status = match Manifest::for_input(&input) {
+                Ok((_, expected)) => {
+                    match answers::check(&input.display().to_string(), &answers, &expected) {
+                        Ok(()) => Status::Pass,
+                        Err(err) => Status::Fail(err.to_string()),
+                    }
+                }
+                Err(_) => Status::Unchecked,
+            };
+            (Some(answers), status)
+        }
+        Ok(Err(err)) => (None, Status::Error(err.to_string())),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (None, Status::Error(format!("panicked: {message}")))
+        }
+    };
+    Outcome {
+        day,
+        input,
+        answers,
+        status,
+    }
+}
+
+// Runs the jobs on `threads` workers which take the next job until none are left
+fn run_jobs<'a>(jobs: Vec<(&'a Day, PathBuf)>, threads: usize) -> Vec<Outcome<'a>> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(vec![]);
+    thread::scope(|s| {
+        for _ in 0..threads.max(1) {
+            s.spawn(|| {
+                while let Some((day, input)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = solve(day, input.clone());
+                    if let Ok(mut outcomes) = outcomes.lock() {
+                        outcomes.push(outcome);
+                    }
+                }
+            });
+        }
+    });
+    outcomes.into_inner().unwrap_or_default()
+}
+
+// an answer cell, a multi-line answer (ex. day 10's CRT) only shows its size
+// and a part without an answer is "-"
+fn cell(answer: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return "-".to_string();
+    };
+    let rows = answer.trim_matches('\n').lines().count();
+    match rows > 1 {
+        true => format!("<{rows} rows>"),
+        false => answer.to_string(),
+    }
+}
+
+fn write_table<W: Write>(out: &mut W, outcomes: &[Outcome]) -> io::Result<()> {
+    let header = [
+        "Day", "Title", "Input", "Part 1", "Part 2", "Status", "Time",
+    ];
+    let rows = outcomes
+        .iter()
+        .map(|o| {
+            let (part1, part2) = match &o.answers {
+                Some(a) => (cell(a.part(1)), cell(a.part(2))),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            let input = o.input.file_name().unwrap_or_default().to_string_lossy();
+            [
+                format!("{:02}", o.day.day),
+                o.day.title.to_string(),
+                input.to_string(),
+                part1,
+                part2,
+                o.status.label().to_string(),
+                o.runtime().map_or("-".to_string(), |t| format!("{t:.2?}")),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = header.map(|h| h.len());
+    for row in &rows {
+        for (w, c) in widths.iter_mut().zip(row) {
+            *w = (*w).max(c.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    writeln!(out, "{}", line(header.to_vec()))?;
+    let dashes = widths.map(|w| "-".repeat(w));
+    writeln!(out, "{}", line(dashes.iter().map(String::as_str).collect()))?;
+    for row in &rows {
+        writeln!(out, "{}", line(row.iter().map(|s| s.as_str()).collect()))?;
+    }
+    Ok(())
+}
+
+// The `summary` subcommand: solve every input of the selected days concurrently and
+// tabulate the answers, whether they match the known answers, and the timings
+pub fn summary(args: &ArgMatches, days: &[Day]) -> Result<(), Box<dyn Error>> {
+    let selected = args.get_one::<Vec<u8>>("days");
+    let names = args
+        .get_many::<String>("input")
+        .map(|names| names.cloned().collect::<Vec<_>>());
+    let jobs = days
+        .iter()
+        .filter(|d| selected.is_none_or(|s| s.contains(&d.day)))
+        .flat_map(|d| day_inputs(d.day).into_iter().map(move |input| (d, input)))
+        .filter(|(_, input)| {
+            let name = input.file_name().unwrap_or_default().to_string_lossy();
+            names.as_ref().is_none_or(|n| n.iter().any(|n| *n == name))
+        })
+        .collect::<Vec<_>>();
+    if jobs.is_empty() {
+        return Err(Box::from("No inputs found for the selected days"));
+    }
+
+    let threads = match args.get_one::<usize>("jobs") {
+        Some(n) => *n,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let timer = Instant::now();
+    let mut outcomes = run_jobs(jobs, threads);
+    let elapsed = timer.elapsed();
+
+    // sorted by day and input order, or slowest first
+    outcomes.sort_by_key(|o| {
+        (
+            o.day.day,
+            o.input.ends_with("input-actual"),
+            o.input.clone(),
+        )
+    });
+    if args.get_one::<String>("sort").is_some_and(|s| s == "time") {
+        outcomes.sort_by_key(|o| std::cmp::Reverse(o.runtime()));
+    }
+
+    let mut stdout = io::stdout().lock();
+    write_table(&mut stdout, &outcomes)?;
+
+    let count = |label: &str| {
+        outcomes
+            .iter()
+            .filter(|o| o.status.label() == label)
+            .count()
+    };
+    writeln!(stdout)?;
+    for o in &outcomes {
+        match &o.status {
+            Status::Fail(diff) => writeln!(stdout, "Day {:02}: {diff}\n", o.day.day)?,
+            Status::Error(err) => writeln!(
+                stdout,
+                "Day {:02} {}: {err}\n",
+                o.day.day,
+                o.input.display()
+            )?,
+            Status::Pass | Status::Unchecked => (),
+        }
+    }
+    writeln!(
+        stdout,
+        "{} inputs: {} passed, {} failed, {} errors, {} unchecked in {elapsed:.2?} with {threads} worker thread(s)",
+        outcomes.len(),
+        count("pass"),
+        count("FAIL"),
+        count("ERROR"),
+        count("-"),
+    )?;
+
+    match count("FAIL") + count("ERROR") {
+        0 => Ok(()),
+        n => Err(Box::from(format!("{n} input(s) failed"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_lists() {
+        assert_eq!(parse_days("1-3, 10"), Ok(vec![1, 2, 3, 10]));
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert!(parse_days("1-x").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! Day 1: Calorie Counting
+use general::generate::{at_least, Generate, Rng};
+use general::{
+    parse_field, parse_lines, parse_records, FromRecord, Param, Params, PuzzleError, Solution,
+};
+
+/// The Calories of the food items carried by an Elf, one item per line
+pub struct Elf {
+    pub calories: u64,
+}
+
+impl FromRecord for Elf {
+    fn from_record(lines: &[String]) -> Result<Self, PuzzleError> {
+        let items = parse_lines(lines, |line| parse_field::<u64>(line, line))?;
+        let calories = items
+            .iter()
+            .try_fold(0u64, |total, c| total.checked_add(*c))
+            .ok_or_else(|| PuzzleError::overflow("an Elf's Calories"))?;
+        Ok(Elf { calories })
+    }
+}
+
+/// Parses the Elves, their items are separated by blank lines
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Elf>, PuzzleError> {
+    parse_records::<Elf>(puzzle_lines)
+}
+
+/// The total Calories carried by the `n` Elves carrying the most
+pub fn count_calories(elves: &[Elf], n: usize) -> Result<u64, PuzzleError> {
+    let mut data = elves.iter().map(|elf| elf.calories).collect::<Vec<_>>();
+    data.sort_by(|a, b| b.cmp(a));
+    data.iter()
+        .take(n)
+        .try_fold(0u64, |total, c| total.checked_add(*c))
+        .ok_or_else(|| PuzzleError::overflow(format_args!("the Calories of the top {n} Elves")))
+}
+
+/// The Calories carried by the Elf carrying the most
+pub fn part1(elves: &[Elf]) -> Result<u64, PuzzleError> {
+    count_calories(elves, 1)
+}
+
+/// The Calories carried by the top three Elves
+pub fn part2(elves: &[Elf]) -> Result<u64, PuzzleError> {
+    count_calories(elves, 3)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const TRIM_LINES: bool = true;
+
+    type Input = Vec<Elf>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day01 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("elves", "250", "the number of Elves"),
+        Param::new::<usize>("items", "15", "the most food items an Elf carries"),
+    ];
+    const SMALL: &'static [&'static str] = &["elves=20"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let elves = at_least("elves", sizes.get("elves")?, 3)?;
+        let items = at_least("items", sizes.get("items")?, 1)?;
+        let mut lines = vec![];
+        for elf in 0..elves {
+            if elf > 0 {
+                lines.push(String::new());
+            }
+            for _ in 0..rng.size(1..=items) {
+                lines.push(rng.range(1000..=60000).to_string());
+            }
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day01);
+}
@@ -0,0 +1,103 @@
+//! Day 2: Rock Paper Scissors
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_lines, Param, Params, PuzzleError, Solution};
+
+const WIN: u64 = 6;
+const DRAW: u64 = 3;
+
+/// A round of the strategy guide, ex. "A Y"
+///
+/// `opponent` is the shape the opponent plays (A, B or C for Rock, Paper or Scissors)
+/// and `column` is the second column (X, Y or Z), both as 0, 1 or 2
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: u8,
+    pub column: u8,
+}
+
+/// Parses the rounds, one per line
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Round>, PuzzleError> {
+    parse_lines(puzzle_lines, |line| match line.as_bytes() {
+        [a @ b'A'..=b'C', b' ', x @ b'X'..=b'Z'] => Ok(Round {
+            opponent: a - b'A',
+            column: x - b'X',
+        }),
+        _ => Err(PuzzleError::parse(line, line, "a round like \"A Y\"")),
+    })
+}
+
+/// The score of a round where the player plays `shape` (0, 1 or 2 for Rock, Paper or Scissors)
+pub fn score(opponent: u8, shape: u8) -> u64 {
+    let outcome = match (3 + shape - opponent) % 3 {
+        0 => DRAW,
+        1 => WIN,
+        _ => 0,
+    };
+    shape as u64 + 1 + outcome
+}
+
+/// The total score when the second column is the shape to play
+pub fn part1(rounds: &[Round]) -> Result<u64, PuzzleError> {
+    Ok(rounds.iter().map(|r| score(r.opponent, r.column)).sum())
+}
+
+/// The total score when the second column is the outcome (X lose, Y draw, Z win)
+pub fn part2(rounds: &[Round]) -> Result<u64, PuzzleError> {
+    Ok(rounds
+        .iter()
+        .map(|r| score(r.opponent, (r.opponent + r.column + 2) % 3))
+        .sum())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const TRIM_LINES: bool = true;
+
+    type Input = Vec<Round>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day02 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "rounds",
+        "2500",
+        "the number of rounds",
+    )];
+    const SMALL: &'static [&'static str] = &["rounds=100"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let rounds = at_least("rounds", sizes.get("rounds")?, 1)?;
+        Ok((0..rounds)
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day02);
+}
@@ -0,0 +1,155 @@
+//! Day 3: Rucksack Reorganization
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_lines, Param, Params, PuzzleError, Solution};
+use std::collections::HashSet;
+
+/// The item types of a rucksack, half in each compartment
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub items: Vec<char>,
+}
+
+impl Rucksack {
+    /// The items of the first and second compartments
+    pub fn compartments(&self) -> (HashSet<char>, HashSet<char>) {
+        let (first, second) = self.items.split_at(self.items.len() / 2);
+        (
+            first.iter().copied().collect(),
+            second.iter().copied().collect(),
+        )
+    }
+}
+
+/// Parses the rucksacks, one per line, the item types are the letters a-z and A-Z
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Rucksack>, PuzzleError> {
+    parse_lines(puzzle_lines, |line| {
+        match line.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(PuzzleError::parse(
+                line,
+                &line[i..],
+                "an item type a-z or A-Z",
+            )),
+            None => Ok(Rucksack {
+                items: line.chars().collect(),
+            }),
+        }
+    })
+}
+
+/// The sum of the priorities of the item types
+pub fn value(set: &HashSet<char>) -> usize {
+    // Lowercase item types a through z have priorities 1 through 26.
+    // Uppercase item types A through Z have priorities 27 through 52.
+    set.iter()
+        .map(|c| match c.is_lowercase() {
+            true => 1 + *c as usize - 'a' as usize,
+            false => 27 + *c as usize - 'A' as usize,
+        })
+        .sum()
+}
+
+/// The sum of the priorities of the items in both compartments of each rucksack
+pub fn part1(rucksacks: &[Rucksack]) -> Result<usize, PuzzleError> {
+    Ok(rucksacks
+        .iter()
+        .map(|r| {
+            let (first, second) = r.compartments();
+            value(&first.intersection(&second).copied().collect())
+        })
+        .sum())
+}
+
+/// The sum of the priorities of the badges, the item common to each group of three Elves
+pub fn part2(rucksacks: &[Rucksack]) -> Result<usize, PuzzleError> {
+    Ok(rucksacks
+        .chunks_exact(3)
+        .map(|group| {
+            let badge = group
+                .iter()
+                .map(|r| r.items.iter().copied().collect::<HashSet<_>>())
+                .reduce(|set, items| set.intersection(&items).copied().collect())
+                .unwrap_or_default();
+            value(&badge)
+        })
+        .sum())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const TRIM_LINES: bool = true;
+
+    type Input = Vec<Rucksack>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day03 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("groups", "100", "the number of groups of three Elves"),
+        Param::new::<usize>(
+            "compartment",
+            "16",
+            "the most items in a compartment, 10 or more",
+        ),
+    ];
+    const SMALL: &'static [&'static str] = &["groups=10"];
+
+    // Each group shares only its badge since the rest of the items are split
+    // between its Elves, and in each rucksack only one item is in both compartments
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let groups = at_least("groups", sizes.get("groups")?, 1)?;
+        let compartment = at_least("compartment", sizes.get("compartment")?, 10)?;
+        let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+        let mut lines = vec![];
+        for _ in 0..groups {
+            rng.shuffle(&mut items);
+            let (badge, rest) = (items[0], &items[1..]);
+            for pool in rest.chunks(17) {
+                let mut carried = pool.to_vec();
+                carried.push(badge);
+                rng.shuffle(&mut carried);
+                // the shared item in both, then 8 items only in the first and 9 only in the second
+                let (shared, first, second) = (carried[0], &carried[1..9], &carried[9..]);
+                let len = rng.size(10..=compartment);
+                let mut fill = |only: &[char]| {
+                    let mut c = vec![shared];
+                    c.extend(only);
+                    while c.len() < len {
+                        c.push(match rng.one_in(only.len() as u64 + 1) {
+                            true => shared,
+                            false => *rng.choose(only),
+                        });
+                    }
+                    rng.shuffle(&mut c);
+                    c
+                };
+                let (mut rucksack, second) = (fill(first), fill(second));
+                rucksack.extend(second);
+                lines.push(rucksack.into_iter().collect());
+            }
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day03);
+}
@@ -0,0 +1,98 @@
+//! Day 4: Camp Cleanup
+use general::generate::{at_least, Generate, Rng};
+use general::intervals::IntervalSet;
+use general::{parse_field, parse_lines, Param, Params, PuzzleError, Solution};
+use std::ops::RangeInclusive;
+
+/// Parses a range of sections, ex. "2-4"
+pub fn range(line: &str, text: &str) -> Result<RangeInclusive<usize>, PuzzleError> {
+    match text.split_once('-') {
+        Some((start, end)) => Ok(parse_field(line, start.trim())?..=parse_field(line, end.trim())?),
+        None => Err(PuzzleError::parse(line, text, "a range \"a-b\"")),
+    }
+}
+
+/// The section assignments for a pair of elves
+pub type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+/// Parses the pairs of assignments, one per line, ex. "2-4,6-8"
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Pair>, PuzzleError> {
+    parse_lines(puzzle_lines, |line| match line.split_once(',') {
+        Some((a, b)) => Ok((range(line, a)?, range(line, b)?)),
+        None => Err(PuzzleError::parse(
+            line,
+            line,
+            "a pair of ranges \"a-b,c-d\"",
+        )),
+    })
+}
+
+/// The number of pairs where one assignment fully contains the other
+pub fn part1(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    Ok(pairs
+        .iter()
+        .filter(|r| {
+            IntervalSet::from(r.0.clone()).contains_range(&r.1)
+                || IntervalSet::from(r.1.clone()).contains_range(&r.0)
+        })
+        .count())
+}
+
+/// The number of pairs whose assignments overlap
+pub fn part2(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    Ok(pairs
+        .iter()
+        .filter(|r| IntervalSet::from(r.0.clone()).overlaps(&r.1))
+        .count())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+    const TRIM_LINES: bool = true;
+
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day04 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("pairs", "1000", "the number of pairs of Elves"),
+        Param::new::<usize>("sections", "99", "the highest section ID"),
+    ];
+    const SMALL: &'static [&'static str] = &["pairs=100"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let pairs = at_least("pairs", sizes.get("pairs")?, 1)?;
+        let sections = at_least("sections", sizes.get("sections")?, 1)?;
+        let mut assignment = || {
+            let (a, b) = (rng.size(1..=sections), rng.size(1..=sections));
+            format!("{}-{}", a.min(b), a.max(b))
+        };
+        Ok((0..pairs)
+            .map(|_| format!("{},{}", assignment(), assignment()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day04);
+}
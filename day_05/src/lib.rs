@@ -0,0 +1,289 @@
+//! Day 5: Supply Stacks
+use general::generate::{at_least, Generate, Rng};
+use general::{paragraphs, parse_field, Param, Params, PuzzleError, Solution};
+use std::collections::BTreeMap;
+
+/// The crates of each stack from the bottom up, keyed by the 0-based stack index
+pub type Stacks = BTreeMap<usize, Vec<char>>;
+
+/// A move instruction with the stacks as 0-based indices, ex. "move 1 from 2 to 1"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub source: usize,
+    pub destination: usize,
+    // the 1-based line of the instruction in the input
+    pub line: usize,
+}
+
+/// The starting stacks and the rearrangement procedure
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+/// Parses the two paragraphs of the input, the drawing of the stacks and the move instructions
+pub fn parse(puzzle_lines: &[String]) -> Result<Procedure, PuzzleError> {
+    let (first_line, lines) = paragraphs(puzzle_lines).nth(1).unwrap_or((0, &[]));
+    let mut moves = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        let n = first_line + i;
+        let (count, source, destination) = parse_move(line).map_err(|e| e.at_line(n))?;
+        moves.push(Move {
+            count,
+            source,
+            destination,
+            line: n,
+        });
+    }
+    Ok(Procedure {
+        stacks: build_stacks(puzzle_lines),
+        moves,
+    })
+}
+
+/// The stacks in the drawing, the first paragraph of the input
+///
+/// Every stack numbered on the last line of the drawing is created, even one which
+/// starts without crates, and then filled from the rows of crates above it
+pub fn build_stacks(puzzle_lines: &[String]) -> Stacks {
+    let mut stacks = BTreeMap::new();
+
+    // the last line of the drawing is the stack numbers
+    let (numbers, drawing) = match paragraphs(puzzle_lines).next() {
+        Some((_, lines)) => lines
+            .split_last()
+            .map_or(("", &[][..]), |(numbers, crates)| {
+                (numbers.as_str(), crates)
+            }),
+        None => ("", &[][..]),
+    };
+    for (i, c) in numbers.chars().skip(1).step_by(4).enumerate() {
+        if c != ' ' {
+            stacks.insert(i, vec![]);
+        }
+    }
+    for line in drawing {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                let v: &mut Vec<char> = stacks.entry(i).or_default();
+                v.insert(0, c)
+            }
+        }
+    }
+    stacks
+}
+
+/// Runs the procedure and returns the crates on top of each stack
+///
+/// The CrateMover 9000 moves crates one at a time, the 9001 (`in_order`)
+/// moves them all at once keeping their order
+pub fn move_crates(procedure: &Procedure, in_order: bool) -> Result<String, PuzzleError> {
+    // ** input-example **
+    //     [D]
+    // [N] [C]
+    // [Z] [M] [P]
+    //  1   2   3
+    //
+    // move 1 from 2 to 1
+    // move 3 from 1 to 3
+    // move 2 from 2 to 1
+    // move 1 from 1 to 2
+
+    //
+    // stacks: {0: ['Z', 'N'], 1: ['M', 'C', 'D'], 2: ['P']}
+    //
+    if procedure.stacks.is_empty() {
+        return Err(PuzzleError::NoSolution(
+            "no crate stacks in the drawing".into(),
+        ));
+    }
+    let mut stacks = procedure.stacks.clone();
+
+    for m in &procedure.moves {
+        // both stacks are checked before any crate is lifted off the source
+        for stack in [m.source, m.destination] {
+            if !stacks.contains_key(&stack) {
+                return Err(PuzzleError::NoSolution(format!(
+                    "line {}: there's no stack {}",
+                    m.line,
+                    stack + 1
+                )));
+            }
+        }
+        let mut tmp = vec![];
+        if let Some(a) = stacks.get_mut(&m.source) {
+            for _ in 0..m.count {
+                let c = a.pop().ok_or_else(|| {
+                    PuzzleError::NoSolution(format!(
+                        "line {}: stack {} is empty",
+                        m.line,
+                        m.source + 1
+                    ))
+                })?;
+                tmp.push(c)
+            }
+        }
+        if in_order {
+            tmp.reverse()
+        }
+        if let Some(b) = stacks.get_mut(&m.destination) {
+            b.extend(tmp)
+        }
+    }
+
+    Ok(stacks.values().filter_map(|v| v.last()).collect::<String>())
+}
+
+/// Parses a move, ex. "move 1 from 2 to 1" => (1, 1, 0)
+/// returns (count, source, destination) with the stacks as 0-based indices
+pub fn parse_move(line: &str) -> Result<(usize, usize, usize), PuzzleError> {
+    let stack = |field: &str| match parse_field::<usize>(line, field)? {
+        0 => Err(PuzzleError::parse(line, field, "a stack number")),
+        n => Ok(n - 1),
+    };
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["move", count, "from", source, "to", destination] => Ok((
+            parse_field(line, count)?,
+            stack(source)?,
+            stack(destination)?,
+        )),
+        _ => Err(PuzzleError::parse(line, line, "move N from A to B")),
+    }
+}
+
+/// The top crates after the CrateMover 9000 moves them one at a time
+pub fn part1(procedure: &Procedure) -> Result<String, PuzzleError> {
+    move_crates(procedure, false)
+}
+
+/// The top crates after the CrateMover 9001 moves them all at once
+pub fn part2(procedure: &Procedure) -> Result<String, PuzzleError> {
+    move_crates(procedure, true)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day05 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("stacks", "9", "the number of stacks, at most 9"),
+        Param::new::<usize>("height", "8", "the most crates in a stack of the drawing"),
+        Param::new::<usize>("moves", "500", "the number of move instructions"),
+    ];
+    const SMALL: &'static [&'static str] = &["moves=50"];
+
+    // The moves are simulated so each only takes crates a stack has
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let stacks = at_least("stacks", sizes.get("stacks")?, 2)?;
+        if stacks > 9 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "stacks={stacks}: must be at most 9"
+            )));
+        }
+        let height = at_least("height", sizes.get("height")?, 1)?;
+        let moves = sizes.get::<usize>("moves")?;
+
+        let mut counts = (0..stacks)
+            .map(|_| rng.size(1..=height))
+            .collect::<Vec<_>>();
+        let mut lines = (0..height)
+            .rev()
+            .map(|row| {
+                counts
+                    .iter()
+                    .map(|&n| match row < n {
+                        true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                        false => "   ".into(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+        lines.push(
+            (1..=stacks)
+                .map(|n| format!(" {n} "))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        lines.push(String::new());
+
+        for _ in 0..moves {
+            let sources = (0..stacks).filter(|&i| counts[i] > 0).collect::<Vec<_>>();
+            let source = *rng.choose(&sources);
+            let mut destination = rng.index(stacks - 1);
+            if destination >= source {
+                destination += 1;
+            }
+            let count = rng.size(1..=counts[source].min(height));
+            counts[source] -= count;
+            counts[destination] += count;
+            lines.push(format!(
+                "move {count} from {} to {}",
+                source + 1,
+                destination + 1
+            ));
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day05);
+
+    // a move to or from a stack which isn't in the drawing is an error, not lost crates
+    #[test]
+    fn missing_stack() -> Result<(), PuzzleError> {
+        for moves in ["move 1 from 1 to 3", "move 1 from 3 to 1"] {
+            let lines = ["[A] [B]", " 1   2", "", moves].map(String::from);
+            let procedure = parse(&lines)?;
+            assert!(matches!(
+                move_crates(&procedure, false),
+                Err(PuzzleError::NoSolution(_))
+            ));
+        }
+        Ok(())
+    }
+
+    // a stack numbered in the drawing is there even when it starts empty
+    #[test]
+    fn empty_stack() -> Result<(), PuzzleError> {
+        let lines = [
+            "[A]     [C]",
+            "[B]     [D]",
+            " 1   2   3",
+            "",
+            "move 2 from 1 to 2",
+        ];
+        let procedure = parse(&lines.map(String::from))?;
+        assert_eq!(procedure.stacks.get(&1), Some(&vec![]));
+        assert_eq!(move_crates(&procedure, false)?, "BC");
+        assert_eq!(move_crates(&procedure, true)?, "AC");
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+//! Day 6: Tuning Trouble
+use general::generate::{at_least, Generate, Rng};
+use general::{Param, Params, PuzzleError, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::io::BufRead;
+
+/// Returns the number of characters processed when the most recent `window` characters
+/// are all different, reading the stream up to the end of the first line
+///
+/// Only the last `window` characters are kept, so any length of stream can be searched, ex.
+/// `find_marker(general::stream_chars(Some(&file)), 14)`
+pub fn find_marker<I>(stream: I, window: usize) -> Result<usize, PuzzleError>
+where
+    I: IntoIterator<Item = Result<char, PuzzleError>>,
+{
+    marker(find_markers(stream, &[window])?[0], window)
+}
+
+/// The first marker of each window size in a single pass over the stream,
+/// None for a window which never has distinct characters
+pub fn find_markers<I>(stream: I, windows: &[usize]) -> Result<Vec<Option<usize>>, PuzzleError>
+where
+    I: IntoIterator<Item = Result<char, PuzzleError>>,
+{
+    if windows.contains(&0) {
+        return Err(PuzzleError::InvalidParameter("window must be > 0".into()));
+    }
+    let longest = windows.iter().copied().max().unwrap_or(0);
+    let mut found = vec![None; windows.len()];
+
+    // the characters of the longest window and the index each was last seen,
+    // with the start of the run of distinct characters ending at the latest
+    let mut recent = VecDeque::with_capacity(longest);
+    let mut last = HashMap::new();
+    let mut start = 0;
+
+    for (i, c) in stream.into_iter().enumerate() {
+        if found.iter().all(Option::is_some) {
+            break;
+        }
+        let c = c?;
+        if c == '\n' || c == '\r' {
+            break;
+        }
+
+        // forgetting a character older than the longest window only makes
+        // the run look longer when it's already long enough for every window
+        if recent.len() == longest {
+            if let Some(old) = recent.pop_front() {
+                if last.get(&old) == Some(&(i - longest)) {
+                    last.remove(&old);
+                }
+            }
+        }
+        recent.push_back(c);
+        if let Some(j) = last.insert(c, i) {
+            start = start.max(j + 1);
+        }
+
+        for (window, marker) in windows.iter().zip(&mut found) {
+            if marker.is_none() && i + 1 - start >= *window {
+                *marker = Some(i + 1);
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The start-of-packet and start-of-message markers of a datastream
+#[derive(Debug)]
+pub struct Markers {
+    packet: Option<usize>,
+    message: Option<usize>,
+}
+
+impl Markers {
+    const PACKET: usize = 4;
+    const MESSAGE: usize = 14;
+
+    /// Searches the stream once for both markers
+    pub fn find<I>(stream: I) -> Result<Self, PuzzleError>
+    where
+        I: IntoIterator<Item = Result<char, PuzzleError>>,
+    {
+        let found = find_markers(stream, &[Self::PACKET, Self::MESSAGE])?;
+        Ok(Self {
+            packet: found[0],
+            message: found[1],
+        })
+    }
+}
+
+// the marker for a window, an error without one
+fn marker(found: Option<usize>, window: usize) -> Result<usize, PuzzleError> {
+    found.ok_or_else(|| {
+        PuzzleError::NoSolution(format!("no window of {window} distinct characters"))
+    })
+}
+
+/// The markers of the datastream buffer, the first line of the input
+pub fn parse(puzzle_lines: &[String]) -> Result<Markers, PuzzleError> {
+    let signal = puzzle_lines.first().map(String::as_str).unwrap_or_default();
+    Markers::find(signal.chars().map(Ok))
+}
+
+/// The characters processed before the start-of-packet marker (4 distinct characters)
+pub fn part1(markers: &Markers) -> Result<usize, PuzzleError> {
+    marker(markers.packet, Markers::PACKET)
+}
+
+/// The characters processed before the start-of-message marker (14 distinct characters)
+pub fn part2(markers: &Markers) -> Result<usize, PuzzleError> {
+    marker(markers.message, Markers::MESSAGE)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    // the binary streams its input so any length of datastream is searched
+    const STREAM_INPUT: bool = true;
+
+    type Input = Markers;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn parse_reader(
+        reader: Box<dyn BufRead>,
+        _params: &Params,
+    ) -> Result<Self::Input, PuzzleError> {
+        Markers::find(general::char_stream(reader))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day06 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "length",
+        "4096",
+        "the number of characters in the datastream, 14 or more",
+    )];
+    const SMALL: &'static [&'static str] = &["length=500"];
+
+    // A character which would make four distinct in a row is usually swapped for
+    // one of the previous three so markers are rare, and the stream ends with
+    // 14 distinct characters so both are found
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let length = at_least("length", sizes.get("length")?, 14)?;
+        let mut letters = ('a'..='z').collect::<Vec<_>>();
+        let mut stream = vec![];
+        for i in 0..length - 14 {
+            let mut c = *rng.choose(&letters);
+            if i >= 3 {
+                let last = &stream[i - 3..];
+                let distinct = last[0] != last[1] && last[1] != last[2] && last[0] != last[2];
+                if distinct && !last.contains(&c) && !rng.one_in(1000) {
+                    c = *rng.choose(last);
+                }
+            }
+            stream.push(c);
+        }
+        rng.shuffle(&mut letters);
+        stream.extend(&letters[..14]);
+        let stream = stream.into_iter().collect::<String>();
+        Ok(vec![stream])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day06);
+
+    #[test]
+    fn markers_in_one_pass() -> Result<(), Box<dyn Error>> {
+        let streams = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", [7, 19]),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", [5, 23]),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", [10, 29]),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", [11, 26]),
+        ];
+        for (stream, expected) in streams {
+            let found = find_markers(stream.chars().map(Ok), &[4, 14, 1])?;
+            assert_eq!(found, [Some(expected[0]), Some(expected[1]), Some(1)]);
+            assert_eq!(find_marker(stream.chars().map(Ok), 4)?, expected[0]);
+        }
+        assert_eq!(
+            find_markers("abcabc".chars().map(Ok), &[3, 4])?,
+            [Some(3), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn part2_actual_stream() -> Result<(), Box<dyn Error>> {
+        let file = std::path::PathBuf::from("input-actual");
+        assert_eq!(find_marker(general::stream_chars(Some(&file)), 14)?, 2260);
+        Ok(())
+    }
+}
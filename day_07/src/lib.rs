@@ -0,0 +1,243 @@
+//! Day 7: No Space Left On Device
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_field, Param, Params, PuzzleError, Solution};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+/// A file or directory (a size of 0) and its full path
+#[derive(Debug)]
+pub enum Item {
+    File(PathBuf, usize),
+}
+impl Item {
+    pub fn size(&self) -> usize {
+        match self {
+            Self::File(_, size) => *size,
+        }
+    }
+}
+
+/// Every file and directory keyed by its full path, ex. "/a/e/i"
+pub type Filesystem = BTreeMap<String, Item>;
+
+/// Replays the terminal session, the `cd` and `ls` commands and their output,
+/// to find the files and directories
+#[rustfmt::skip]
+pub fn build_filesystem_view(commands: &[String]) -> Result<Filesystem, PuzzleError> {
+    let mut fs = BTreeMap::new();
+    let mut root = PathBuf::new();
+    root.push("/");
+    fs.insert(root.display().to_string(), Item::File(root.clone(), 0));
+
+    for (i, line) in commands.iter().enumerate() {
+        if line.starts_with("$ ls") {
+            continue;
+        }
+
+        let mut path = PathBuf::new();
+        path.push(root.clone());
+
+        // hash key: path as a string
+        let hashkey = |path: PathBuf| path.display().to_string();
+
+        if let Some(arg) = line.strip_prefix("$ cd") {
+            // update path
+            let arg = arg.trim_start();
+            match arg {
+                ".." => { path.pop(); }
+                arg => { path.push(arg); }
+            }
+
+            // set the root pointer from the path
+            let pathkey = hashkey(path.clone());
+            root = match fs.get(&pathkey) {
+                Some(Item::File(path, _)) => path.to_path_buf(),
+                _ => return Err(PuzzleError::parse(line, arg, "a listed directory").at_line(i + 1)),
+            };
+        } else {
+            let mut listing = line.split_whitespace();
+            if let (Some(attr), Some(name)) = (listing.next(), listing.next()) {
+                // update path
+                path.push(name);
+
+                // insert the full path of the item with a size: dir(0) or filesize
+                let pathkey = hashkey(path.clone());
+                let size = match attr == "dir" {
+                    true => 0,
+                    false => parse_field(line, attr).map_err(|e| e.at_line(i + 1))?,
+                };
+                fs.insert(pathkey, Item::File(path, size));
+            }
+        }
+    }
+
+    Ok(fs)
+}
+
+/// The total size of the files under a path, ex. "/a/"
+pub fn get_dir_size(dir: &str, fs: &Filesystem) -> usize {
+    fs.iter()
+        .filter(|(path, _)| path.starts_with(dir))
+        .map(|(_, item)| item.size())
+        .sum::<usize>()
+}
+
+/// The total size under each path in the filesystem
+pub fn get_dir_sizes(fs: &Filesystem) -> Vec<usize> {
+    fs.keys()
+        .map(|path| get_dir_size(&(path.to_owned() + "/"), fs))
+        .collect::<Vec<_>>()
+}
+
+/// The sum of the sizes of the directories of at most 100000
+pub fn part1(session: &Session) -> Result<usize, PuzzleError> {
+    Ok(get_dir_sizes(&session.fs)
+        .iter()
+        .filter(|s| *s <= &100000)
+        .sum::<usize>())
+}
+
+/// The size of the smallest directory which frees enough space for the update
+pub fn part2(session: &Session) -> Result<usize, PuzzleError> {
+    let Session { fs, disk, needed } = session;
+    let (disk, needed) = (*disk, *needed);
+    let free = disk.checked_sub(get_dir_size("/", fs)).ok_or_else(|| {
+        PuzzleError::NoSolution(format!("the files don't fit on a disk of {disk}"))
+    })?;
+    get_dir_sizes(fs)
+        .iter()
+        .filter(|s| free + *s >= needed)
+        .copied()
+        .min()
+        .ok_or_else(|| PuzzleError::NoSolution("no directory frees enough space".into()))
+}
+
+/// The filesystem and the sizes of the disk and the free space the update needs
+pub struct Session {
+    pub fs: Filesystem,
+    pub disk: usize,
+    pub needed: usize,
+}
+
+/// Parses the terminal session with the disk and update sizes of the parameters
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Session, PuzzleError> {
+    Ok(Session {
+        fs: build_filesystem_view(puzzle_lines)?,
+        disk: params.get("disk")?,
+        needed: params.get("needed")?,
+    })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<usize>("disk", "70000000", "the size of the disk"),
+        Param::new::<usize>("needed", "30000000", "the free space the update needs"),
+    ];
+
+    type Input = Session;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day07 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("dirs", "180", "the number of directories besides /"),
+        Param::new::<usize>("files", "300", "the number of files"),
+    ];
+    const SMALL: &'static [&'static str] = &["dirs=20", "files=40"];
+
+    // The files add up to between 41000000 and 69000000 so with the default
+    // disk and needed parameters the update needs space freeing, and one of
+    // 29000000 or more under the first directory of / is enough to free
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let dirs = at_least("dirs", sizes.get("dirs")?, 1)? + 1;
+        let files = at_least("files", sizes.get("files")?, 1)?;
+
+        // the parent of each directory but /, and the entries of each directory
+        let mut entries = vec![vec![]; dirs];
+        let mut names = vec![HashSet::new(); dirs];
+        let mut unique_name = |rng: &mut Rng, dir: usize, suffix: &str| loop {
+            let name = rng.name(1..=8) + suffix;
+            if names[dir].insert(name.clone()) {
+                return name;
+            }
+        };
+        for d in 1..dirs {
+            let parent = rng.index(d);
+            let name = unique_name(rng, parent, "");
+            entries[parent].push(Entry::Dir(name, d));
+        }
+        let big = rng.size(29_000_000..=30_000_000);
+        let name = unique_name(rng, 1, ".bin");
+        entries[1].push(Entry::File(name, big));
+
+        let weights = (1..files).map(|_| rng.size(1..=1000)).collect::<Vec<_>>();
+        let total = rng.size(41_000_000..=69_000_000) - big;
+        let weight = weights.iter().sum::<usize>();
+        for w in weights {
+            let dir = rng.index(dirs);
+            let suffix = *rng.choose(&["", ".txt", ".dat", ".log"]);
+            let name = unique_name(rng, dir, suffix);
+            entries[dir].push(Entry::File(name, (w * total / weight).max(1)));
+        }
+
+        let mut lines = vec!["$ cd /".to_string()];
+        list(rng, 0, &mut entries, &mut lines);
+        Ok(lines)
+    }
+}
+
+// a directory or a file and its size in a generated filesystem
+#[derive(Clone)]
+enum Entry {
+    Dir(String, usize),
+    File(String, usize),
+}
+
+// the terminal session listing a directory and then each of its subdirectories
+fn list(rng: &mut Rng, dir: usize, entries: &mut [Vec<Entry>], lines: &mut Vec<String>) {
+    let mut listing = std::mem::take(&mut entries[dir]);
+    rng.shuffle(&mut listing);
+    lines.push("$ ls".into());
+    for entry in &listing {
+        lines.push(match entry {
+            Entry::Dir(name, _) => format!("dir {name}"),
+            Entry::File(name, size) => format!("{size} {name}"),
+        });
+    }
+    for entry in listing {
+        if let Entry::Dir(name, d) = entry {
+            lines.push(format!("$ cd {name}"));
+            list(rng, d, entries, lines);
+            lines.push("$ cd ..".into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day07);
+}
@@ -0,0 +1,115 @@
+//! Day 8: Treetop Tree House
+use general::generate::{at_least, Generate, Rng};
+use general::grid::Grid;
+use general::{Param, Params, PuzzleError, Solution};
+
+/// Parses the grid of tree heights, one digit per tree
+pub fn get_grid(data: &[String]) -> Result<Grid<u32>, PuzzleError> {
+    const RADIX: u32 = 10;
+    Grid::parse_with(data, "a digit", |c| c.to_digit(RADIX))
+}
+
+/// The tree heights looking out from (i, j) in each direction: left, right, up, down
+pub fn views(grid: &Grid<u32>, i: usize, j: usize) -> [Vec<u32>; 4] {
+    let row = grid.row(i);
+    [
+        row[..j].iter().rev().copied().collect(),
+        row[j + 1..].to_vec(),
+        grid.col(j).take(i).rev().copied().collect(),
+        grid.col(j).skip(i + 1).copied().collect(),
+    ]
+}
+
+/// The number of trees visible from outside the grid
+///
+/// A tree is visible if it's taller than all the trees between it and an edge,
+/// trees on the edge are visible
+pub fn visible_count(grid: &Grid<u32>) -> usize {
+    grid.iter()
+        .filter(|((i, j), n)| {
+            views(grid, *i, *j)
+                .iter()
+                .any(|view| view.iter().all(|v| v < n))
+        })
+        .count()
+}
+
+/// The highest scenic score, the product of the viewing distances in each direction
+pub fn scenic_score(grid: &Grid<u32>) -> usize {
+    fn count_view(n: u32, vals: &[u32]) -> usize {
+        match vals.iter().position(|v| *v >= n) {
+            Some(c) => c + 1,
+            None => vals.len(),
+        }
+    }
+
+    grid.iter()
+        .map(|((i, j), n)| {
+            views(grid, i, j)
+                .iter()
+                .map(|view| count_view(*n, view))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// The number of trees visible from outside the grid
+pub fn part1(grid: &Grid<u32>) -> Result<usize, PuzzleError> {
+    Ok(visible_count(grid))
+}
+
+/// The highest scenic score of any tree
+pub fn part2(grid: &Grid<u32>) -> Result<usize, PuzzleError> {
+    Ok(scenic_score(grid))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_grid(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day08 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "size",
+        "99",
+        "the width and height of the grid",
+    )];
+    const SMALL: &'static [&'static str] = &["size=20"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let size = at_least("size", sizes.get("size")?, 1)?;
+        Ok((0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| (b'0' + rng.below(10) as u8) as char)
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day08);
+}
@@ -0,0 +1,143 @@
+//! Day 9: Rope Bridge
+use general::generate::{at_least, Generate, Rng};
+use general::geom::{Dir4, Point2};
+use general::{parse_field, parse_lines, Param, Params, PuzzleError, Solution};
+use std::collections::HashSet;
+
+/// The position of a knot of the rope
+pub type Knot = Point2<i32>;
+
+/// A motion of the head of the rope, ex. "R 4"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Dir4,
+    pub distance: usize,
+}
+
+/// Parses the motions, one per line, blank lines are skipped
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Motion>, PuzzleError> {
+    let motions = parse_lines(puzzle_lines, |line| {
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [] => Ok(None),
+            [direction, distance] => Ok(Some(Motion {
+                direction: parse_field(line, direction)?,
+                distance: parse_field(line, distance)?,
+            })),
+            _ => Err(PuzzleError::parse(line, line, "a motion like \"R 4\"")),
+        }
+    })?;
+    Ok(motions.into_iter().flatten().collect())
+}
+
+/// The tail stays put while touching the head (overlapping or adjacent, including diagonally),
+/// otherwise it steps one unit toward the head in each axis
+pub fn get_pos(t: Knot, m: Knot) -> Knot {
+    match t.chebyshev(&m) {
+        0 | 1 => t,
+        _ => t + (m - t).signum(),
+    }
+}
+
+/// Moves the head of a rope to `m`, each following knot follows the one before it
+pub fn move_head(v: &[Knot], m: Knot) -> Vec<Knot> {
+    let mut newvec = vec![m];
+    for t in v.iter().skip(1) {
+        let head = newvec[newvec.len() - 1];
+        newvec.push(get_pos(*t, head))
+    }
+    newvec
+}
+
+/// The most steps the head is moved, the knots are stepped one at a time
+/// (the inputs take about 10000) and the positions of the rope are i32
+pub const MAX_STEPS: usize = 10_000_000;
+
+/// The number of positions the tail of a rope of `n` knots visits
+pub fn coverage(motions: &[Motion], n: usize) -> Result<usize, PuzzleError> {
+    if n == 0 {
+        return Err(PuzzleError::InvalidParameter("a rope needs a knot".into()));
+    }
+    let steps = motions
+        .iter()
+        .try_fold(0usize, |steps, m| steps.checked_add(m.distance))
+        .filter(|steps| *steps <= MAX_STEPS);
+    if steps.is_none() {
+        return Err(PuzzleError::NoSolution(format!(
+            "the motions take more than {MAX_STEPS} steps"
+        )));
+    }
+    let mut mat = HashSet::new();
+    let mut knots = vec![Knot::default(); n];
+
+    mat.insert(knots[n - 1]);
+    for motion in motions {
+        let step = motion.direction.delta();
+        for _ in 0..motion.distance {
+            knots = move_head(&knots, knots[0] + step);
+            mat.insert(knots[n - 1]);
+        }
+    }
+    Ok(mat.len())
+}
+
+/// The positions the tail of a rope of 2 knots visits
+pub fn part1(motions: &[Motion]) -> Result<usize, PuzzleError> {
+    coverage(motions, 2)
+}
+
+/// The positions the tail of a rope of 10 knots visits
+pub fn part2(motions: &[Motion]) -> Result<usize, PuzzleError> {
+    coverage(motions, 10)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<Motion>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day09 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("motions", "2000", "the number of motions"),
+        Param::new::<usize>("steps", "19", "the most steps in a motion"),
+    ];
+    const SMALL: &'static [&'static str] = &["motions=200"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let motions = sizes.get::<usize>("motions")?;
+        let steps = at_least("steps", sizes.get("steps")?, 1)?;
+        Ok((0..motions)
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&['U', 'D', 'L', 'R']),
+                    rng.size(1..=steps)
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day09);
+}
@@ -0,0 +1,185 @@
+//! Day 10: Cathode-Ray Tube
+use general::generate::{Generate, Rng};
+use general::{parse_field, parse_lines, Param, Params, PuzzleError, Solution};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// An instruction of the CPU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// takes one cycle
+    Noop,
+    /// takes two cycles then adds its value to the X register
+    Addx(i32),
+}
+
+/// Parses the program, one instruction per line, ex. "addx -5" or "noop"
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Instruction>, PuzzleError> {
+    parse_lines(puzzle_lines, |line| {
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["noop"] => Ok(Instruction::Noop),
+            ["addx", value] => Ok(Instruction::Addx(parse_field(line, value)?)),
+            _ => Err(PuzzleError::parse(line, line, "addx V or noop")),
+        }
+    })
+}
+
+// adds an addx value to the X register
+fn addx(register: i32, value: i32) -> Result<i32, PuzzleError> {
+    register
+        .checked_add(value)
+        .ok_or_else(|| PuzzleError::overflow(format_args!("the X register {register} + {value}")))
+}
+
+/// The value of the X register during each cycle as (cycle, X), cycles count from 1
+pub fn get_cycles(program: &[Instruction]) -> Result<Vec<(usize, i32)>, PuzzleError> {
+    let mut clock: usize = 0;
+    let mut register = 1;
+    let mut cycles = vec![];
+
+    for instruction in program {
+        clock += 1;
+        if let Instruction::Addx(value) = instruction {
+            cycles.push((clock, register));
+            register = addx(register, *value)?;
+            clock += 1
+        }
+        cycles.push((clock, register));
+    }
+    Ok(cycles)
+}
+
+/// The sum of the signal strengths (the cycle times X) during the 20th, 60th, 100th, ... cycles
+pub fn signal_strength(program: &[Instruction]) -> Result<i32, PuzzleError> {
+    get_cycles(program)?
+        .windows(2)
+        .filter(|cycle| cycle[1].0 == 20 || ((cycle[1].0 as i64) - 20) % 40 == 0)
+        .try_fold(0i32, |sum, cycle| {
+            i32::try_from(cycle[1].0)
+                .ok()
+                .and_then(|c| c.checked_mul(cycle[0].1))
+                .and_then(|strength| sum.checked_add(strength))
+        })
+        .ok_or_else(|| PuzzleError::overflow("the sum of the signal strengths"))
+}
+
+/// The lit pixels of each row the CRT draws, a pixel is lit when the 3 pixel wide sprite
+/// centered on X covers it
+pub fn crt(program: &[Instruction]) -> Result<Vec<BTreeSet<usize>>, PuzzleError> {
+    let crt_width = 40;
+    let mut clock: usize = 0;
+    let mut register = 1;
+    let mut sprite = 0..=3;
+    let mut lit = BTreeSet::new();
+    let mut screen = vec![];
+
+    for instruction in program {
+        let t = clock % crt_width;
+        if sprite.contains(&(t as i32)) {
+            lit.insert(t);
+        }
+        clock += 1;
+
+        if let Instruction::Addx(value) = instruction {
+            if clock.is_multiple_of(40) {
+                screen.push(lit.clone());
+                lit.clear();
+            }
+            let t = clock % crt_width;
+            if sprite.contains(&(t as i32)) {
+                lit.insert(t);
+            }
+            clock += 1;
+
+            register = addx(register, *value)?;
+            sprite = register.saturating_sub(1)..=register.saturating_add(1)
+        }
+        if clock.is_multiple_of(crt_width) {
+            screen.push(lit.clone());
+            lit.clear()
+        }
+    }
+    Ok(screen)
+}
+
+/// The lit pixels of each CRT row, displayed as rows of '#'
+pub struct Screen(pub Vec<BTreeSet<usize>>);
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for set in &self.0 {
+            writeln!(f)?;
+            for p in 0..40 {
+                write!(f, "{}", if set.contains(&p) { "#" } else { " " })?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The sum of the six signal strengths
+pub fn part1(program: &[Instruction]) -> Result<i32, PuzzleError> {
+    signal_strength(program)
+}
+
+/// The image the CRT draws, its letters are the answer
+pub fn part2(program: &[Instruction]) -> Result<Screen, PuzzleError> {
+    crt(program).map(Screen)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = Screen;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day10 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "cycles",
+        "240",
+        "the number of cycles the program runs",
+    )];
+
+    // The X register stays within the 40 pixels of a row
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let cycles = sizes.get::<usize>("cycles")?;
+        let (mut cycle, mut x) = (0, 1);
+        let mut lines = vec![];
+        while cycle < cycles {
+            if cycle + 2 <= cycles && !rng.one_in(3) {
+                let v = rng.range(-x..=39 - x);
+                x += v;
+                cycle += 2;
+                lines.push(format!("addx {v}"));
+            } else {
+                cycle += 1;
+                lines.push("noop".into());
+            }
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day10);
+}
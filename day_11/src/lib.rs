@@ -0,0 +1,313 @@
+//! Day 11: Monkey in the Middle
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_field, parse_records, FromRecord, Param, Params, PuzzleError, Solution};
+use regex::Regex;
+
+/// A monkey's notes
+#[rustfmt::skip]
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    pub id: usize,                // monkey
+    pub items: Vec<u64>,          // Starting items lists your worry level for each item the
+                                  // monkey is currently holding in the order they will be inspected.
+    pub operation_op: String,     // operation shows how your worry level changes
+                                  // as that monkey inspects an item.
+                                  // (An operation like new = old * 5 means that your worry level
+                                  // after the monkey inspected the item is five times whatever your
+                                  // worry level was before inspection.)
+    pub operation_var: Option<u64>,
+    pub test: u64,                // Test shows how the monkey uses your worry
+                                  // level to decide where to throw an item next.
+    pub if_true: usize,           // If true shows what happens with an item if the Test was true.
+    pub if_false: usize,          // If false shows what happens with an item if the Test was false.
+}
+impl Monkey {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            items: vec![],
+            operation_op: "".into(),
+            operation_var: None,
+            test: 0,
+            if_true: 0,
+            if_false: 0,
+        }
+    }
+    /// The monkey an item is thrown to and its new worry level after an inspection,
+    /// kept modulo `lcm` (a multiple of every monkey's test)
+    pub fn calc(&self, item: u64, decrease_worry_factor: u64, lcm: u64) -> (usize, u64) {
+        // u128 holds the product of any two u64 worry levels
+        let item = item as u128;
+        let var = match self.operation_var {
+            Some(num) => num as u128,
+            _ => item, // "old"
+        };
+
+        let worry_level = match self.operation_op == "*" {
+            true => item * var / decrease_worry_factor as u128,
+            _ => (item + var) / decrease_worry_factor as u128,
+        };
+        let worry_level = (worry_level % lcm as u128) as u64;
+
+        match worry_level % self.test {
+            0 => (self.if_true, worry_level),
+            _ => (self.if_false, worry_level),
+        }
+    }
+}
+
+impl FromRecord for Monkey {
+    // ex.
+    //   Monkey 0:
+    //     Starting items: 79, 98
+    //     Operation: new = old * 19
+    //     Test: divisible by 23
+    //       If true: throw to monkey 2
+    //       If false: throw to monkey 3
+    fn from_record(lines: &[String]) -> Result<Self, PuzzleError> {
+        let monkey_re = Regex::new(r"Monkey (\d+):").unwrap();
+        let items_re = Regex::new(r"^\s+Starting items: (.*)").unwrap();
+        let operation_re = Regex::new(r"^\s+Operation: new = old\s+(\S+)\s+(\S+)").unwrap();
+        let test_re = Regex::new(r"^\s+Test: divisible by (\d+)").unwrap();
+        let if_true_re = Regex::new(r"^\s+If true: throw to monkey (\d+)").unwrap();
+        let if_false_re = Regex::new(r"^\s+If false: throw to monkey (\d+)").unwrap();
+
+        let mut monkey = Monkey::default();
+
+        for (i, line) in lines.iter().enumerate() {
+            // the captured fields are subslices of the line for error reporting
+            let field =
+                |re: &Regex, n: usize| re.captures(line).and_then(|c| c.get(n)).map(|m| m.as_str());
+
+            let parsed = if let Some(id) = field(&monkey_re, 1) {
+                parse_field(line, id).map(|id| monkey.id = id)
+            } else if let Some(items) = field(&items_re, 1) {
+                items
+                    .split(',')
+                    .map(|n| parse_field::<u64>(line, n.trim()))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| monkey.items = items)
+            } else if let (Some(op), Some(var)) = (field(&operation_re, 1), field(&operation_re, 2))
+            {
+                match (op, var) {
+                    ("*" | "+", "old") => Ok(None),
+                    ("*" | "+", var) => parse_field(line, var).map(Some),
+                    _ => Err(PuzzleError::parse(line, op, "an operator * or +")),
+                }
+                .map(|var| {
+                    monkey.operation_op = op.to_string();
+                    monkey.operation_var = var;
+                })
+            } else if let Some(test) = field(&test_re, 1) {
+                parse_field(line, test).map(|test| monkey.test = test)
+            } else if let Some(id) = field(&if_true_re, 1) {
+                parse_field(line, id).map(|id| monkey.if_true = id)
+            } else if let Some(id) = field(&if_false_re, 1) {
+                parse_field(line, id).map(|id| monkey.if_false = id)
+            } else {
+                Err(PuzzleError::parse(
+                    line,
+                    line.trim_start(),
+                    "a monkey description",
+                ))
+            };
+            parsed.map_err(|e| e.at_line(i + 1))?;
+        }
+        Ok(monkey)
+    }
+}
+
+/// Parses the monkeys, their descriptions are separated by blank lines,
+/// every monkey needs a test and has to throw to monkeys in the notes
+pub fn get_monkeys(puzzle_lines: &[String]) -> Result<Vec<Monkey>, PuzzleError> {
+    let monkeys = parse_records::<Monkey>(puzzle_lines)?;
+    for monkey in &monkeys {
+        if monkey.test == 0 {
+            return Err(PuzzleError::NoSolution(format!(
+                "monkey {} has no test (divisible by 0)",
+                monkey.id
+            )));
+        }
+        if let Some(j) = [monkey.if_true, monkey.if_false]
+            .into_iter()
+            .find(|j| *j >= monkeys.len())
+        {
+            return Err(PuzzleError::NoSolution(format!(
+                "monkey {} throws to monkey {j} which isn't in the notes",
+                monkey.id
+            )));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// The level of monkey business after the rounds, the product of the two highest numbers
+/// of inspections, worry levels are divided by 3 after an inspection in part 1
+pub fn inspect(monkeys: &[Monkey], rounds: usize, part: u8) -> Result<usize, PuzzleError> {
+    // puzzle part 1: divide by 3
+    // puzzle part 2: no decrease (divide by 1)
+    let decrease_worry_factor = if part == 1 { 3 } else { 1 };
+
+    // multiply the mod tests for all monkeys, e.g. 13 * 17 * 19 * 23
+    let lcm = monkeys
+        .iter()
+        .try_fold(1u64, |lcm, m| lcm.checked_mul(m.test))
+        .ok_or_else(|| PuzzleError::overflow("the product of the tests"))?;
+
+    let mut monkeys = monkeys.to_vec();
+    let mut inspected = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            // count items to throw (per monkey)
+            inspected[i] += monkeys[i].items.len();
+
+            // monkey business
+            for item in monkeys[i].items.clone() {
+                let (j, worry_level) = monkeys[i].calc(item, decrease_worry_factor, lcm);
+                monkeys[j].items.push(worry_level)
+            }
+
+            // all items were thrown
+            monkeys[i].items.clear()
+        }
+    }
+
+    inspected.sort_by(|a, b| b.cmp(a));
+    match inspected[..] {
+        [a, b, ..] => a
+            .checked_mul(b)
+            .ok_or_else(|| PuzzleError::overflow("the monkey business")),
+        _ => Err(PuzzleError::NoSolution(
+            "monkey business needs two monkeys".into(),
+        )),
+    }
+}
+
+/// The monkey business after `rounds1` rounds with relief
+pub fn part1(notes: &Notes) -> Result<usize, PuzzleError> {
+    inspect(&notes.monkeys, notes.rounds1, 1)
+}
+
+/// The monkey business after `rounds2` rounds without relief
+pub fn part2(notes: &Notes) -> Result<usize, PuzzleError> {
+    inspect(&notes.monkeys, notes.rounds2, 2)
+}
+
+/// The monkeys and the number of rounds of each part
+pub struct Notes {
+    pub monkeys: Vec<Monkey>,
+    pub rounds1: usize,
+    pub rounds2: usize,
+}
+
+/// Parses the monkeys with the rounds of the parameters
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Notes, PuzzleError> {
+    Ok(Notes {
+        monkeys: get_monkeys(puzzle_lines)?,
+        rounds1: params.get("rounds1")?,
+        rounds2: params.get("rounds2")?,
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<usize>("rounds1", "20", "the rounds of monkey business in part 1"),
+        Param::new::<usize>(
+            "rounds2",
+            "10000",
+            "the rounds of monkey business in part 2",
+        ),
+    ];
+
+    type Input = Notes;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day11 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("monkeys", "8", "the number of monkeys, 2 to 12"),
+        Param::new::<usize>("items", "8", "the most items a monkey starts with"),
+    ];
+
+    // Each monkey tests a different prime, one squares the worry level
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        const PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        let monkeys = at_least("monkeys", sizes.get("monkeys")?, 2)?;
+        if monkeys > PRIMES.len() {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "monkeys={monkeys}: must be at most {}",
+                PRIMES.len()
+            )));
+        }
+        let items = at_least("items", sizes.get("items")?, 1)?;
+        let mut tests = PRIMES[..monkeys].to_vec();
+        rng.shuffle(&mut tests);
+        let squares = rng.index(monkeys);
+        let other = |rng: &mut Rng, id: usize| {
+            let m = rng.index(monkeys - 1);
+            match m >= id {
+                true => m + 1,
+                false => m,
+            }
+        };
+
+        let mut lines = vec![];
+        for (id, test) in tests.into_iter().enumerate() {
+            let start = (0..rng.size(1..=items))
+                .map(|_| rng.size(50..=99).to_string())
+                .collect::<Vec<_>>();
+            let operation = match (id == squares, rng.one_in(2)) {
+                (true, _) => "old * old".to_string(),
+                (false, true) => format!("old * {}", rng.size(2..=19)),
+                (false, false) => format!("old + {}", rng.size(1..=8)),
+            };
+            let if_true = other(rng, id);
+            let mut if_false = other(rng, id);
+            while monkeys > 2 && if_false == if_true {
+                if_false = other(rng, id);
+            }
+            if id > 0 {
+                lines.push(String::new());
+            }
+            lines.extend([
+                format!("Monkey {id}:"),
+                format!("  Starting items: {}", start.join(", ")),
+                format!("  Operation: new = {operation}"),
+                format!("  Test: divisible by {test}"),
+                format!("    If true: throw to monkey {if_true}"),
+                format!("    If false: throw to monkey {if_false}"),
+            ]);
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day11);
+}
@@ -0,0 +1,198 @@
+//! Day 12: Hill Climbing Algorithm
+use general::generate::{at_least, Generate, Rng};
+use general::graph::bfs;
+use general::grid::Grid;
+use general::{Param, Params, PuzzleError, Solution};
+
+// parse the input to generate:
+//   1. a 2-d grid of unsigned integers
+//   2. the `start` and `end` coordinates
+//
+// data types:
+//   1. the matrix is a general::grid::Grid
+//   2. the `start` and `end` coordinates are tuples `(usize, usize)`
+//
+// the puzzle is to connect `start` to `end` by transitions (up, down, left, right)
+// and find the shortest path
+//
+// a transition is legal unless the destination (up, down, left right) value
+// is greater than the current value by more than 1
+//
+// the story treats it as hill climbing
+//
+/// Parses the heightmap, its start 'S' has the height of 'a' and its end 'E' that of 'z'
+pub fn parse_input(data: &[String]) -> Result<Heightmap, PuzzleError> {
+    // puzzle input represents a matrix of heights by lowercase characters
+    // `start` is 'S' with value 'a'
+    // `end` is 'E' with value 'z'
+    // Example:
+    //     Sabqponm
+    //     abcryxxl
+    //     accszExk
+    //     acctuvwj
+    //     abdefghi
+    //
+    // Reference
+    //   ['A', 'Z] == [65, 90]
+    //   ['a', 'z'] == [97, 122]
+    //
+    let mut mat = Grid::parse_with(data, "a height", |c| Some(c as usize))?;
+
+    // record start and reset 'S' to 'a'
+    let start = mat
+        .position(|v| *v == 'S' as usize)
+        .ok_or_else(|| PuzzleError::NoSolution("no start 'S' in the input".into()))?;
+    mat[start] = 'a' as usize;
+
+    // record end and reset 'E' to 'z'
+    let end = mat
+        .position(|v| *v == 'E' as usize)
+        .ok_or_else(|| PuzzleError::NoSolution("no end 'E' in the input".into()))?;
+    mat[end] = 'z' as usize;
+
+    Ok(Heightmap {
+        grid: mat,
+        start,
+        end,
+    })
+}
+
+/// The heights of the area as characters, ex. 'a' as usize, and the start and end positions
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    pub grid: Grid<usize>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+// neighbors matching transition contraint
+//
+// a transition is legal unless the neighbor (up, down, left right) value
+// is greater than the position value by more than 1
+//
+// a list of legal indices is returned
+pub fn neighbors(m: &Grid<usize>, p: (usize, usize)) -> Vec<(usize, usize)> {
+    let maxval = m[p] + 1;
+    m.neighbors4(p).filter(|n| m[*n] <= maxval).collect()
+}
+
+/// The fewest steps to the end from the start in part 1,
+/// or from any position as low as the start in part 2
+pub fn solve(map: &Heightmap, part: usize) -> Result<usize, PuzzleError> {
+    // m = matrix of heights
+    // s = starting coordinate
+    // e = ending coordinate goal
+    let (m, s, e) = (&map.grid, map.start, map.end);
+    //
+    // bfs from the starting position(s) at distance 0
+    let starts = match part {
+        1 => vec![s],
+        // any/all coordinates matching the start value
+        _ => m
+            .iter()
+            .filter(|(_, v)| **v == m[s])
+            .map(|(coord, _)| coord)
+            .collect(),
+    };
+
+    bfs(starts, |p| neighbors(m, *p), |p| *p == e)
+        .cost()
+        .ok_or_else(|| PuzzleError::NoSolution("no path from 'S' to 'E'".into()))
+}
+
+/// The fewest steps from 'S' to 'E'
+pub fn part1(map: &Heightmap) -> Result<usize, PuzzleError> {
+    solve(map, 1)
+}
+
+/// The fewest steps from any square of height 'a' to 'E'
+pub fn part2(map: &Heightmap) -> Result<usize, PuzzleError> {
+    solve(map, 2)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input = Heightmap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse_input(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day12 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("rows", "41", "the number of rows"),
+        Param::new::<usize>("columns", "136", "the number of columns"),
+    ];
+    const SMALL: &'static [&'static str] = &["rows=10", "columns=20"];
+
+    // A trail snaking across the rows from S climbs from 'a' to 'z' at E
+    // a step at a time, the rest of the heights are random
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let rows = at_least("rows", sizes.get("rows")?, 1)?;
+        let columns = at_least("columns", sizes.get("columns")?, 1)?;
+        if rows * columns < 26 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "rows={rows}, columns={columns}: the climb from 'a' to 'z' needs at least 26 squares"
+            )));
+        }
+        let mut grid = (0..rows)
+            .map(|_| {
+                (0..columns)
+                    .map(|_| b'a' + rng.below(26) as u8)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (flip_r, flip_c) = (rng.one_in(2), rng.one_in(2));
+        let square = |i: usize| {
+            let (r, c) = (i / columns, i % columns);
+            let c = if r % 2 == 1 { columns - 1 - c } else { c };
+            (
+                if flip_r { rows - 1 - r } else { r },
+                if flip_c { columns - 1 - c } else { c },
+            )
+        };
+        let len = rng.size(26..=rows * columns);
+        let mut climbs = vec![true; 25];
+        climbs.resize(len - 1, false);
+        rng.shuffle(&mut climbs);
+        let mut height = b'a';
+        for i in 0..len {
+            if i > 0 && climbs[i - 1] {
+                height += 1;
+            }
+            let (r, c) = square(i);
+            grid[r][c] = height;
+        }
+        let (r, c) = square(0);
+        grid[r][c] = b'S';
+        let (r, c) = square(len - 1);
+        grid[r][c] = b'E';
+        Ok(grid
+            .into_iter()
+            .map(|row| row.into_iter().map(char::from).collect())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day12);
+}
@@ -0,0 +1,188 @@
+//! Day 13: Distress Signal
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_lines, parse_records, FromRecord, Param, Params, PuzzleError, Solution};
+use std::cmp::Ordering;
+
+#[macro_use]
+extern crate json;
+
+/// A packet or a value in one, a list of integers and lists
+pub type List = json::JsonValue;
+// using json::JsonValue as a List abstraction
+//
+// the subset of JsonValue methods/macros used:
+//    JsonValue.is_number()
+//    JsonValue.is_array()
+//    JsonValue.is_null()
+//    JsonValue.as_u64()
+//    macro array![] to create a new list
+
+// a packet is a list of integers and lists
+fn is_packet(value: &List) -> bool {
+    value.is_array()
+        && value
+            .members()
+            .all(|v| v.as_u64().is_some() || is_packet(v))
+}
+
+/// Parses a packet, ex. `[1,[2,[3,[4,[5,6,7]]]],8,9]`
+pub fn packet(line: &str) -> Result<List, PuzzleError> {
+    match json::parse(line) {
+        Ok(list) if is_packet(&list) => Ok(list),
+        Ok(_) => Err(PuzzleError::parse(line, line, "a packet")),
+        Err(json::Error::UnexpectedCharacter { column, .. }) => {
+            let found = line
+                .char_indices()
+                .nth(column - 1)
+                .map_or("", |(c, _)| &line[c..]);
+            Err(PuzzleError::parse(line, found, "a packet"))
+        }
+        Err(e) => Err(PuzzleError::parse(line, line, format!("a packet ({e})"))),
+    }
+}
+
+/// A pair of packets, pairs are separated by a blank line
+pub struct Pair(pub List, pub List);
+
+impl FromRecord for Pair {
+    fn from_record(lines: &[String]) -> Result<Self, PuzzleError> {
+        match parse_lines(lines, packet)?.as_slice() {
+            [left, right] => Ok(Pair(left.clone(), right.clone())),
+            _ => {
+                let line = &lines[lines.len().min(3) - 1];
+                Err(PuzzleError::parse(line, line, "a pair of packets").at_line(lines.len().min(3)))
+            }
+        }
+    }
+}
+
+/// Parses the pairs of packets
+pub fn get_data(data: &[String]) -> Result<Vec<Pair>, PuzzleError> {
+    parse_records(data)
+}
+
+/// The order of two packets, integers compare by value, lists element by element
+/// with the shorter list first when one runs out, and an integer against a list
+/// compares as a list of the integer
+pub fn compare(left: &List, right: &List) -> Ordering {
+    match (left, right) {
+        (l, r) if l.is_number() && r.is_number() => l.as_u64().cmp(&r.as_u64()),
+        (l, r) if l.is_array() && r.is_array() => {
+            for i in 0..l.len().max(r.len()) {
+                if l[i].is_null() && r[i].is_null() {
+                    return Ordering::Equal;
+                } else if l[i].is_null() {
+                    return Ordering::Less;
+                } else if r[i].is_null() {
+                    return Ordering::Greater;
+                }
+
+                let ordering = compare(&l[i], &r[i]);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        }
+        (l, r) if l.is_number() => compare(&array![l.as_u64()], r),
+        (l, r) => {
+            assert!(r.is_number());
+            compare(l, &array![r.as_u64()])
+        }
+    }
+}
+
+/// The sum of the (1-based) indices of the pairs in the right order
+pub fn part1(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    Ok(pairs
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, compare(&p.0, &p.1)))
+        .filter(|(_, c)| *c == Ordering::Less)
+        .map(|(i, _)| i + 1)
+        .sum())
+}
+
+/// The decoder key, the product of the positions of the divider packets `[[2]]` and `[[6]]`
+/// after sorting every packet
+pub fn part2(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    let markers = [array!([[2]]), array!([[6]])];
+
+    let mut packets = pairs
+        .iter()
+        .flat_map(|p| [p.0.clone(), p.1.clone()])
+        .chain(markers.clone())
+        .collect::<Vec<_>>();
+
+    packets.sort_by(compare);
+
+    Ok(packets
+        .into_iter()
+        .enumerate()
+        .filter(|(_, p)| markers.contains(p))
+        .map(|(i, _)| i + 1)
+        .product())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_data(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day13 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("pairs", "150", "the number of pairs of packets"),
+        Param::new::<usize>("depth", "4", "the most levels of nested lists"),
+    ];
+    const SMALL: &'static [&'static str] = &["pairs=30"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let pairs = at_least("pairs", sizes.get("pairs")?, 1)?;
+        let depth = sizes.get::<usize>("depth")?;
+        let mut lines = vec![];
+        for i in 0..pairs {
+            if i > 0 {
+                lines.push(String::new());
+            }
+            lines.push(random_packet(rng, depth));
+            lines.push(random_packet(rng, depth));
+        }
+        Ok(lines)
+    }
+}
+
+// a list of up to 5 integers 0..=10 and lists nested up to `depth` more levels
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.size(0..=5))
+        .map(|_| match depth > 0 && rng.one_in(3) {
+            true => random_packet(rng, depth - 1),
+            false => rng.size(0..=10).to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day13);
+}
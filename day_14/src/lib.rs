@@ -0,0 +1,279 @@
+//! Day 14: Regolith Reservoir
+use general::generate::{at_least, Generate, Rng};
+use general::grid::Grid;
+use general::{parse_field, Param, Params, PuzzleError, Solution};
+use std::ops::RangeInclusive;
+
+/// Parses a point of a rock path, ex. "503,4" => (503, 4)
+pub fn point(line: &str, text: &str) -> Result<(usize, usize), PuzzleError> {
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| PuzzleError::parse(line, text, "a point \"x,y\""))?;
+    Ok((parse_field(line, x.trim())?, parse_field(line, y.trim())?))
+}
+
+/// A horizontal or vertical line of rock between two (x, y) points, ex. ((498, 4), (498, 6))
+pub type Line = ((usize, usize), (usize, usize));
+
+/// The lines of rock
+pub type Rocks = Vec<Line>;
+
+/// Parses the rock paths, one per line, into the lines of rock they trace
+pub fn get_data(data: &[String]) -> Result<Rocks, PuzzleError> {
+    let mut rocks = vec![];
+    for (i, line) in data.iter().enumerate() {
+        // ex. "503,4 -> 502,4 -> 502,9 -> 494,9"
+        let points = line
+            .split("->")
+            // ex. ["503,4", "502,4", "502,9", "494,9"]
+            .map(|s| point(line, s.trim()).map(|p| (s.trim(), p)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.at_line(i + 1))?;
+
+        // ex. [(503, 4), (502, 4), (502, 9), (494, 9)]
+        for p in points.windows(2) {
+            let ((x1, y1), (text, (x2, y2))) = (p[0].1, p[1]);
+            if x1 != x2 && y1 != y2 {
+                return Err(
+                    PuzzleError::parse(line, text, "a horizontal or vertical line").at_line(i + 1),
+                );
+            }
+            rocks.push(((x1, y1), (x2, y2)));
+        }
+    }
+    Ok(rocks)
+}
+
+/// The sand source
+pub const SOURCE: (usize, usize) = (500, 0);
+
+/// A cave scan with rock '#', air '.' and resting sand 'o'
+pub struct Cave {
+    pub grid: Grid<char>,
+    // the x coordinate of the leftmost column
+    pub left: usize,
+}
+
+impl Cave {
+    /// Sized to hold the sand piled up to the floor, which is never further than `floor`
+    /// either side of the source so rock beyond that is left out. The cave's area grows
+    /// with the square of the floor's depth and it's an error if it's over `cells`
+    pub fn new(rocks: &Rocks, floor: usize, cells: usize) -> Result<Self, PuzzleError> {
+        let left = SOURCE.0.saturating_sub(floor);
+        let right = SOURCE.0.saturating_add(floor);
+        let (nrows, ncols) = (floor.saturating_add(1), right - left + 1);
+        if nrows.checked_mul(ncols).is_none_or(|n| n > cells) {
+            return Err(PuzzleError::NoSolution(format!(
+                "the cave down to the floor at y={floor} is more than cells={cells} cells"
+            )));
+        }
+
+        let mut grid = Grid::from_elem(nrows, ncols, '.')
+            .ok_or_else(|| PuzzleError::overflow("the size of the cave"))?;
+        for ((x1, y1), (x2, y2)) in rocks {
+            let xs = (*x1.min(x2)).max(left)..=(*x1.max(x2)).min(right);
+            for x in xs {
+                for y in *y1.min(y2)..=*y1.max(y2) {
+                    grid[(y, x - left)] = '#';
+                }
+            }
+        }
+        Ok(Self { grid, left })
+    }
+
+    /// The cell at an (x, y) position, None is outside the scan
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<char> {
+        x.checked_sub(self.left)
+            .and_then(|col| self.grid.get((y, col)))
+            .copied()
+    }
+
+    pub fn set(&mut self, (x, y): (usize, usize), c: char) {
+        self.grid[(y, x - self.left)] = c
+    }
+}
+
+/// The units of sand which come to rest before sand falls into the abyss in part 1,
+/// or before the source is blocked with a floor 2 below the deepest rock in part 2
+pub fn solve(scan: &Scan, part: usize) -> Result<usize, PuzzleError> {
+    let mut max_depth = scan
+        .rocks
+        .iter()
+        .map(|((_, y1), (_, y2))| *y1.max(y2))
+        .max()
+        .ok_or_else(|| PuzzleError::NoSolution("no rocks in the input".into()))?;
+    let floor = max_depth
+        .checked_add(2)
+        .ok_or_else(|| PuzzleError::overflow("the depth of the floor"))?;
+    let mut cave = Cave::new(&scan.rocks, floor, scan.cells)?;
+    if part == 2 {
+        max_depth += 2;
+        cave.grid.row_mut(max_depth).fill('#');
+    }
+
+    let mut p = SOURCE;
+    while p.1 < max_depth {
+        let y = p.1 + 1;
+
+        // down, down-left, down-right
+        let next = [Some(p.0), p.0.checked_sub(1), Some(p.0 + 1)]
+            .into_iter()
+            .flatten()
+            .map(|x| (x, y))
+            .find(|q| cave.get(*q) == Some('.'));
+
+        match next {
+            Some(q) => p = q,
+            None => {
+                cave.set(p, 'o');
+                if p == SOURCE {
+                    break;
+                }
+                p = SOURCE
+            }
+        }
+    }
+
+    Ok(cave.grid.iter().filter(|(_, c)| **c == 'o').count())
+}
+
+/// The units of sand at rest before sand flows into the abyss
+pub fn part1(scan: &Scan) -> Result<usize, PuzzleError> {
+    solve(scan, 1)
+}
+
+/// The units of sand at rest when the source is blocked
+pub fn part2(scan: &Scan) -> Result<usize, PuzzleError> {
+    solve(scan, 2)
+}
+
+/// The rock and the most cells the cave may have
+pub struct Scan {
+    pub rocks: Rocks,
+    pub cells: usize,
+}
+
+/// Parses the rock paths with the cells of the parameters
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Scan, PuzzleError> {
+    Ok(Scan {
+        rocks: get_data(puzzle_lines)?,
+        cells: params.get("cells")?,
+    })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    const PARAMS: &'static [Param] = &[Param::new::<usize>(
+        "cells",
+        "10000000",
+        "the most cells of the cave, about 2 * (deepest rock y)^2",
+    )];
+
+    type Input = Scan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day14 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("paths", "150", "the number of rock paths"),
+        Param::new::<usize>("depth", "170", "the deepest y of the rock, at most 900"),
+    ];
+    const SMALL: &'static [&'static str] = &["paths=20", "depth=40"];
+
+    // Paths of 2 to 6 points between x 450 and 550 and below the source
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let paths = sizes.get::<usize>("paths")?;
+        let depth = at_least("depth", sizes.get("depth")?, 5)?;
+        if depth > 900 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "depth={depth}: must be at most 900"
+            )));
+        }
+        let (xs, ys) = (450..=550, 2..=depth);
+        let step = |rng: &mut Rng, v: usize, range: &RangeInclusive<usize>| {
+            let d = rng.size(1..=8.min((range.end() - range.start()) / 2));
+            match v + d <= *range.end() && (rng.one_in(2) || v < range.start() + d) {
+                true => v + d,
+                false => v - d,
+            }
+        };
+        let mut lines = vec![];
+        for _ in 0..paths {
+            let (mut x, mut y) = (rng.size(xs.clone()), rng.size(ys.clone()));
+            let mut points = vec![format!("{x},{y}")];
+            let horizontal = rng.one_in(2);
+            for i in 0..rng.size(1..=5) {
+                match (i % 2 == 0) == horizontal {
+                    true => x = step(rng, x, &xs),
+                    false => y = step(rng, y, &ys),
+                }
+                points.push(format!("{x},{y}"));
+            }
+            lines.push(points.join(" -> "));
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day14);
+
+    fn scan(extra: &[&str], cells: &str) -> Result<Scan, PuzzleError> {
+        let lines = [
+            "498,4 -> 498,6 -> 496,6",
+            "503,4 -> 502,4 -> 502,9 -> 494,9",
+        ]
+        .iter()
+        .chain(extra)
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+        parse(
+            &lines,
+            &Params::new(Day14::PARAMS, &[format!("cells={cells}")])?,
+        )
+    }
+
+    // rock out of reach of the sand is left out of the cave, however far away
+    #[test]
+    fn distant_rock() -> Result<(), PuzzleError> {
+        // a row of rock right under the source, clipped to the cave
+        let far = scan(
+            &["3000,5 -> 3000,8", "18446744073709551615,1 -> 0,1"],
+            "1000",
+        )?;
+        assert_eq!((part1(&far)?, part2(&far)?), (1, 1));
+        let far = scan(&["3000,5 -> 3000,8", "1000000,2 -> 600,2"], "1000")?;
+        assert_eq!((part1(&far)?, part2(&far)?), (24, 93));
+
+        // sand falls 1500 rows to the abyss, and the cells limit the cave
+        let deep = scan(&["100,1500 -> 101,1500"], "10000000")?;
+        assert_eq!(part1(&deep)?, 24);
+        let small = scan(&[], "100")?;
+        assert!(matches!(part1(&small), Err(PuzzleError::NoSolution(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+//! Day 15: Beacon Exclusion Zone
+use general::generate::{at_least, Generate, Rng};
+use general::geom::Point2;
+use general::intervals::IntervalSet;
+use general::{parse_lines, scan, Param, Params, PuzzleError, Solution};
+use std::collections::HashSet;
+
+/// A sensor, its closest beacon and their Manhattan distance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sensor {
+    pub pos: Point2<i64>,
+    pub beacon: Point2<i64>,
+    pub d: i64,
+}
+
+/// Parses the sensor report, ex. "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+///
+/// the positions are i32 so the distances and coverage ranges can't overflow an i64
+pub fn get_data(data: &[String]) -> Result<Vec<Sensor>, PuzzleError> {
+    parse_lines(data, |line| {
+        let (x1, y1, x2, y2) = scan!(
+            line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}" => i32, i32, i32, i32
+        )?;
+        let pos = Point2::new(x1.into(), y1.into());
+        let beacon = Point2::new(x2.into(), y2.into());
+        let d = pos.manhattan(&beacon);
+        Ok(Sensor { pos, beacon, d })
+    })
+}
+
+// Part1 solving at the expense of space because I'll be generating
+// all the points that intersect a line for each sensor and collecting
+// them into a set.  The size of the set is the final answer
+//
+// two points (x1, y1), (x2, y2) which represent a sensor and its beacon.
+//
+// sensors is a Vec of Sensor holding both points and d, the Manhattan distance
+#[allow(dead_code)]
+fn solve1_not_scaleable(sensors: &[Sensor], row: usize) -> Result<usize, PuzzleError> {
+    let row = row as i64;
+
+    let mut s = HashSet::new();
+    for sensor in sensors.iter() {
+        let (x1, y1, x2, y2, d) = (
+            sensor.pos.x,
+            sensor.pos.y,
+            sensor.beacon.x,
+            sensor.beacon.y,
+            sensor.d,
+        );
+
+        if (d - y1).abs() > row {
+            continue;
+        }
+
+        let mag = d - (row - y1).abs();
+        if mag > 0 {
+            // generate the points in ranges x1 += mag skipping any beacons on the line
+            for i in x1 - mag..=x1 + mag {
+                // don't count the beacon if it's in the input row
+                if y2 != row || i != x2 {
+                    s.insert(i);
+                }
+            }
+        }
+    }
+    Ok(s.len())
+}
+
+/// The positions on a row where a beacon can't be, the union of the sensors' coverage
+/// of the row less the beacons on it
+pub fn solve1(sensors: &[Sensor], row: usize) -> Result<usize, PuzzleError> {
+    let row = row as i64;
+
+    let mut row_coverage = IntervalSet::new();
+    for sensor in sensors.iter() {
+        let (x1, y1, d) = (sensor.pos.x, sensor.pos.y, sensor.d);
+
+        // collect the coverage ranges on the row (x1 += mag)
+        if row >= y1 - d && row <= y1 + d {
+            let mag = d - (row - y1).abs();
+            if mag > 0 {
+                row_coverage.insert(x1 - mag..=x1 + mag);
+            }
+        }
+    }
+
+    // beacons on the row, a beacon straight above or below its sensor isn't in the coverage
+    let beacons = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.y == row && row_coverage.contains(&sensor.beacon.x))
+        .map(|sensor| sensor.beacon)
+        .collect::<HashSet<_>>();
+
+    // the row_coverage length, don't count beacons
+    Ok(row_coverage.total_len() as usize - beacons.len())
+}
+
+/// The tuning frequency (x * 4000000 + y) of the only position within 0..=maxp on both
+/// axes which no sensor covers, found as a gap in the coverage of a row and of a column
+pub fn solve2(sensors: &[Sensor], maxp: usize) -> Result<usize, PuzzleError> {
+    let maxpi = maxp as i64;
+
+    let mut x = None;
+    let mut y = None;
+
+    for i in (0..maxpi).rev() {
+        let mut row_coverage = IntervalSet::new();
+        let mut col_coverage = IntervalSet::new();
+
+        for sensor in sensors.iter() {
+            let (x1, y1, d) = (sensor.pos.x, sensor.pos.y, sensor.d);
+
+            // collect the coverage ranges on the row (x1 += mag)
+            if y.is_none() && i >= y1 - d && i <= y1 + d {
+                let mag = d - (i - y1).abs();
+                if mag > 0 {
+                    row_coverage.insert(0.max(x1 - mag)..=maxpi.min(x1 + mag));
+                }
+            }
+
+            // collect the coverage ranges on the col (y1 += mag)
+            if x.is_none() && i >= x1 - d && i <= x1 + d {
+                let mag = d - (i - x1).abs();
+                if mag > 0 {
+                    col_coverage.insert(0.max(y1 - mag)..=maxpi.min(y1 + mag));
+                }
+            }
+        }
+
+        // a gap in the coverage is the distress beacon's row (or col)
+        if y.is_none() && row_coverage.gaps().next().is_some() {
+            y = Some(i as usize);
+        }
+
+        if x.is_none() && col_coverage.gaps().next().is_some() {
+            x = Some(i as usize);
+        }
+
+        if let (Some(x), Some(y)) = (x, y) {
+            return x
+                .checked_mul(4000000)
+                .and_then(|f| f.checked_add(y))
+                .ok_or_else(|| PuzzleError::overflow("the tuning frequency"));
+        }
+    }
+
+    Err(PuzzleError::NoSolution(format!(
+        "no uncovered position within 0..={maxp}"
+    )))
+}
+
+/// The positions where a beacon can't be on the `row` of the scan
+pub fn part1(scan: &Scan) -> Result<usize, PuzzleError> {
+    solve1(&scan.sensors, scan.row)
+}
+
+/// The tuning frequency of the distress beacon within 0..=`bound` of the scan
+pub fn part2(scan: &Scan) -> Result<usize, PuzzleError> {
+    solve2(&scan.sensors, scan.bound)
+}
+
+/// The sensor report, the row counted in part 1 and the bound of the search in part 2
+pub struct Scan {
+    pub sensors: Vec<Sensor>,
+    pub row: usize,
+    pub bound: usize,
+}
+
+/// Parses the sensor report with the row and bound of the parameters
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Scan, PuzzleError> {
+    Ok(Scan {
+        sensors: get_data(puzzle_lines)?,
+        row: params.get("row")?,
+        bound: params.get("bound")?,
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<usize>("row", "2000000", "the row where part 1 counts positions"),
+        Param::new::<usize>(
+            "bound",
+            "4000000",
+            "the distress beacon's x and y are 0..=bound",
+        ),
+    ];
+
+    type Input = Scan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day15 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("sensors", "25", "the number of sensors, 4 or more"),
+        Param::new::<usize>(
+            "bound",
+            "4000000",
+            "the distress beacon's x and y are 0..=bound, solve with the same --param bound",
+        ),
+    ];
+    const SMALL: &'static [&'static str] = &["sensors=8", "bound=100"];
+
+    // A sensor beyond each corner of the search area reaches to next to the
+    // distress beacon, so it's the only position in the area they don't cover,
+    // and the other sensors' beacons are closer to them than the distress beacon
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let sensors = at_least("sensors", sizes.get("sensors")?, 4)?;
+        let bound = at_least("bound", sizes.get("bound")?, 2)?;
+        if bound > 1_000_000_000 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "bound={bound}: must be at most 1000000000"
+            )));
+        }
+        let bound = bound as i64;
+        let margin = bound / 10 + 1;
+        let distress = Point2::new(rng.range(1..=bound - 1), rng.range(1..=bound - 1));
+
+        let mut positions = [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .into_iter()
+            .map(|(sx, sy)| {
+                let mut offset = || rng.range(1..=margin);
+                let x = if sx < 0 { -offset() } else { bound + offset() };
+                let y = if sy < 0 { -offset() } else { bound + offset() };
+                (Point2::new(x, y), None)
+            })
+            .collect::<Vec<_>>();
+        while positions.len() < sensors {
+            let pos = Point2::new(
+                rng.range(-margin..=bound + margin),
+                rng.range(-margin..=bound + margin),
+            );
+            let d = pos.manhattan(&distress);
+            if d >= 2 {
+                positions.push((pos, Some(rng.range(1..=d - 1))));
+            }
+        }
+        rng.shuffle(&mut positions);
+
+        Ok(positions
+            .into_iter()
+            .map(|(pos, radius)| {
+                let r = radius.unwrap_or_else(|| pos.manhattan(&distress) - 1);
+                let dx = rng.range(0..=r);
+                let sign = |rng: &mut Rng| *rng.choose(&[-1, 1]);
+                let beacon = Point2::new(pos.x + sign(rng) * dx, pos.y + sign(rng) * (r - dx));
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    pos.x, pos.y, beacon.x, beacon.y
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day15);
+
+    #[test]
+    fn negative_sensor() -> Result<(), Box<dyn Error>> {
+        let line = "Sensor at x=-2, y=-18: closest beacon is at x=-3, y=15".to_string();
+        let sensors = super::get_data(&[line])?;
+        assert_eq!((sensors[0].pos, sensors[0].d), (Point2::new(-2, -18), 34));
+        Ok(())
+    }
+}
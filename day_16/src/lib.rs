@@ -0,0 +1,323 @@
+//! Day 16: Proboscidea Volcanium
+//!
+// using Floyd-Warshall (general::graph::floyd_warshall) as seen here:
+// https://github.com/betaveros/advent-of-code-2022/blob/main/p16.noul
+//
+use general::generate::{at_least, Generate, Rng};
+use general::graph::floyd_warshall;
+use general::parse::scan;
+use general::{parse_field, Param, Params, PuzzleError, Solution};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Each valve's flow rate and the valves its tunnels lead to
+pub type Valves = BTreeMap<String, (usize, Vec<String>)>;
+
+/// Parses the scan, one valve per line, every tunnel has to lead to a valve of the scan
+pub fn get_data(data: &[String]) -> Result<Valves, PuzzleError> {
+    // ex.  Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    // ex.  Valve HH has flow rate=22; tunnel leads to valve GG
+    let mut valves = BTreeMap::new();
+    let mut tunnels = vec![];
+    for (i, line) in data.iter().enumerate() {
+        let fields = scan(line, "Valve {} has flow rate={}; {}").map_err(|e| e.at_line(i + 1))?;
+        let valve = fields[0].to_string();
+        let rate = parse_field::<usize>(line, fields[1]).map_err(|e| e.at_line(i + 1))?;
+        let leads_to = fields[2]
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| fields[2].strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| {
+                PuzzleError::parse(line, fields[2], "tunnel(s) lead(s) to valve(s) V, ...")
+                    .at_line(i + 1)
+            })?
+            .split(',')
+            .map(|s| s.trim())
+            .collect::<Vec<_>>();
+        valves.insert(
+            valve,
+            (rate, leads_to.iter().map(|s| s.to_string()).collect()),
+        );
+        tunnels.push((i, line, leads_to));
+    }
+
+    // every tunnel has to lead to a known valve
+    for (i, line, leads_to) in tunnels {
+        if let Some(unknown) = leads_to.into_iter().find(|s| !valves.contains_key(*s)) {
+            return Err(PuzzleError::parse(line, unknown, "a known valve").at_line(i + 1));
+        }
+    }
+    Ok(valves)
+}
+
+/// The shortest distances between the valves, their flow rates and the valves worth opening,
+/// the valves are in name order so valve 0 is AA where you start
+pub struct Volcano {
+    pub dist: Vec<Vec<Option<usize>>>,
+    pub flows: Vec<usize>,
+    pub indices: Vec<usize>,
+}
+
+/// Parses the scan into the distances between its valves
+pub fn parse(puzzle_lines: &[String]) -> Result<Volcano, PuzzleError> {
+    let mut valves = vec![];
+    let mut flows = vec![];
+    let mut tunnels = HashMap::new();
+
+    // get_data() returns this BTreeMap() on the example input
+    // {"AA": (0, ["DD", "II", "BB"]),
+    //  "BB": (13, ["CC", "AA"]),
+    //  "CC": (2, ["DD", "BB"]),
+    //  "DD": (20, ["CC", "AA", "EE"]),
+    //  "EE": (3, ["FF", "DD"]),
+    //  "FF": (0, ["EE", "GG"]),
+    //  "GG": (0, ["FF", "HH"]),
+    //  "HH": (22, ["GG"]),
+    //  "II": (0, ["AA", "JJ"]),
+    //  "JJ": (21, ["II"])}
+    for (valve, (flow, neighbors)) in &get_data(puzzle_lines)? {
+        valves.push(valve.clone());
+        flows.push(*flow);
+        tunnels.insert(valve.clone(), neighbors.clone());
+    }
+
+    // shortest distances between all the valves
+    let index = valves
+        .iter()
+        .enumerate()
+        .map(|(i, valve)| (valve.as_str(), i))
+        .collect::<HashMap<_, _>>();
+    let edges = valves.iter().enumerate().flat_map(|(i, valve)| {
+        tunnels[valve]
+            .iter()
+            .map(|neighbor| (i, index[neighbor.as_str()], 1))
+            .collect::<Vec<_>>()
+    });
+    let dist = floyd_warshall(valves.len(), edges).dist;
+
+    // non-zero flows
+    let indices = flows
+        .iter()
+        .enumerate()
+        .filter(|(_, &f)| f > 0)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    Ok(Volcano {
+        dist,
+        flows,
+        indices,
+    })
+}
+
+/// The most pressure released in 30 minutes alone in part 1,
+/// or in 26 minutes with the elephant's help in part 2
+pub fn solve(volcano: &Volcano, part: usize) -> usize {
+    let Volcano {
+        dist,
+        flows,
+        indices,
+    } = volcano;
+    let cache1 = &mut Cache::new();
+    let cache2 = &mut Cache::new();
+    match part {
+        1 => dfs1(dist, flows, 0, indices, 30, cache1),
+        2 => dfs2(dist, flows, 0, indices, 26, cache1, cache2),
+        _ => unreachable!("part 1 or 2"),
+    }
+}
+
+/// The best pressure released from a (valve, closed valves, time left) state
+pub type Cache = HashMap<(usize, Vec<usize>, usize), usize>;
+
+// example: input &[1, 2, 3, 4] returns an iterator over:
+//   (1, [2, 3, 4])
+//   (2, [1, 3, 4])
+//   (3, [1, 2, 4])
+//   (4, [1, 2, 3])
+fn choose_one(v: &[usize]) -> impl Iterator<Item = (&usize, Vec<usize>)> {
+    v.iter().zip((0..v.len()).map(|i| {
+        [&v[..i], &v[i + 1..]]
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect()
+    }))
+}
+
+// the time left after moving from valve `cur` to valve `i` and opening it,
+// None if `i` is unreachable or too far away
+fn time_left(dist: &[Vec<Option<usize>>], cur: usize, i: usize, time: usize) -> Option<usize> {
+    dist[cur][i].filter(|d| *d < time).map(|d| time - d - 1)
+}
+
+/// The most pressure released moving from valve `cur` and opening the `indices` valves
+/// in the time left
+pub fn dfs1(
+    dist: &[Vec<Option<usize>>],
+    flows: &[usize],
+    cur: usize,
+    indices: &[usize],
+    time: usize,
+    cache: &mut Cache,
+) -> usize {
+    if let Some(val) = cache.get(&(cur, indices.into(), time)) {
+        return *val;
+    }
+
+    let mut maxval = 0;
+    for (i, list) in choose_one(indices) {
+        if let Some(dt) = time_left(dist, cur, *i, time) {
+            maxval = maxval.max(flows[*i] * dt + dfs1(dist, flows, *i, &list, dt, cache));
+        }
+    }
+
+    cache.insert((cur, indices.into(), time), maxval);
+    maxval
+}
+
+/// dfs1 for you, with the elephant starting from AA on whichever valves you leave closed
+pub fn dfs2(
+    dist: &[Vec<Option<usize>>],
+    flows: &[usize],
+    cur: usize,
+    indices: &[usize],
+    time: usize,
+    dfs1_cache: &mut Cache,
+    dfs2_cache: &mut Cache,
+) -> usize {
+    if let Some(val) = dfs2_cache.get(&(cur, indices.into(), time)) {
+        return *val;
+    }
+
+    let mut maxval = 0;
+    for (i, list) in choose_one(indices) {
+        if let Some(dt) = time_left(dist, cur, *i, time) {
+            maxval = maxval
+                .max(flows[*i] * dt + dfs2(dist, flows, *i, &list, dt, dfs1_cache, dfs2_cache));
+        }
+    }
+
+    maxval = match dfs1_cache.get(&(0, indices.to_vec(), 26)) {
+        Some(n) => maxval.max(*n),
+        _ => maxval.max(dfs1(dist, flows, 0, indices, 26, dfs1_cache)),
+    };
+
+    dfs2_cache.insert((cur, indices.into(), time), maxval);
+    maxval
+}
+
+/// The most pressure you can release alone
+pub fn part1(volcano: &Volcano) -> Result<usize, PuzzleError> {
+    Ok(solve(volcano, 1))
+}
+
+/// The most pressure you and the elephant can release
+pub fn part2(volcano: &Volcano) -> Result<usize, PuzzleError> {
+    Ok(solve(volcano, 2))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Proboscidea Volcanium";
+
+    type Input = Volcano;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day16 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("valves", "60", "the number of valves, at most 676"),
+        Param::new::<usize>("flowing", "15", "the number of valves with a flow rate"),
+    ];
+    const SMALL: &'static [&'static str] = &["valves=12", "flowing=6"];
+
+    // The tunnels connect every valve to AA, which has no flow, and lead both ways
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let valves = at_least("valves", sizes.get("valves")?, 2)?;
+        if valves > 26 * 26 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "valves={valves}: must be at most {}",
+                26 * 26
+            )));
+        }
+        let flowing = sizes.get::<usize>("flowing")?;
+        if flowing >= valves {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "flowing={flowing}: must be less than valves={valves}"
+            )));
+        }
+
+        let mut names = (0..26 * 26)
+            .map(|n| {
+                format!(
+                    "{}{}",
+                    (b'A' + (n / 26) as u8) as char,
+                    (b'A' + (n % 26) as u8) as char
+                )
+            })
+            .skip(1)
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        names.truncate(valves - 1);
+        names.insert(0, "AA".into());
+
+        let mut flows = vec![0; valves];
+        for flow in flows.iter_mut().skip(1).take(flowing) {
+            *flow = rng.size(2..=25);
+        }
+        rng.shuffle(&mut flows[1..]);
+
+        // a random spanning tree and a few more tunnels
+        let mut tunnels = vec![BTreeSet::new(); valves];
+        let mut connect = |a: usize, b: usize| {
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        };
+        for v in 1..valves {
+            connect(v, rng.index(v));
+        }
+        for _ in 0..valves / 4 {
+            connect(rng.index(valves), rng.index(valves));
+        }
+
+        let mut lines = (0..valves)
+            .map(|v| {
+                let mut leads = tunnels[v]
+                    .iter()
+                    .map(|&t| names[t].as_str())
+                    .collect::<Vec<_>>();
+                rng.shuffle(&mut leads);
+                let leads = match leads[..] {
+                    [one] => format!("tunnel leads to valve {one}"),
+                    _ => format!("tunnels lead to valves {}", leads.join(", ")),
+                };
+                format!("Valve {} has flow rate={}; {leads}", names[v], flows[v])
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day16);
+}
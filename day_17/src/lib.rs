@@ -0,0 +1,319 @@
+//! Day 17: Pyroclastic Flow
+use general::cycle::Cycle;
+use general::generate::{at_least, Generate, Rng};
+use general::geom::Dir4;
+use general::grid::Grid;
+use general::{Param, Params, PuzzleError, Solution};
+use std::collections::HashMap;
+
+/// The chamber is 7 units wide, row 0 is the floor and rows are added as the rocks pile up
+pub const WIDTH: usize = 7;
+
+/// Parses the jet pattern, the jets push rocks left '<' or right '>'
+pub fn get_data(data: &[String]) -> Result<Vec<Dir4>, PuzzleError> {
+    let line = data.first().map_or("", |s| s.trim_end());
+    let jets = line
+        .char_indices()
+        .map(|(i, c)| match Dir4::try_from(c) {
+            Ok(d) if c == '<' || c == '>' => Ok(d),
+            _ => {
+                Err(PuzzleError::parse(line, &line[i..i + c.len_utf8()], "a jet < or >").at_line(1))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match jets.is_empty() {
+        true => Err(PuzzleError::parse(line, line, "a jet pattern").at_line(1)),
+        false => Ok(jets),
+    }
+}
+
+/// The 5 rock shapes as (x, y) cells in the order they fall, offset `x_offset` from the left wall
+#[rustfmt::skip]
+pub fn get_rocks(x_offset: usize) -> Vec<Vec<(usize, usize)>> {
+    let rock1 = vec![ (0,0), (1,0), (2,0), (3,0) ];
+
+    let rock2 = vec![        (1,2),
+                      (0,1), (1,1), (2,1),
+                             (1,0)         ];
+
+    let rock3 = vec![               (2,2),
+                                    (2,1),
+                      (0,0), (1,0), (2,0)  ];
+
+    let rock4 = vec![ (0,3),
+                      (0,2),
+                      (0,1),
+                      (0,0)  ];
+
+    let rock5 = vec![ (0,1), (1,1),
+                      (0,0), (1,0) ];
+
+    let rocks = [rock1, rock2, rock3, rock4, rock5];
+    rocks.iter()
+        .map(|r| r.iter().map(|(x, y)| (*x + x_offset, *y)).collect::<Vec<_>>())
+        .collect::<Vec<_>>()
+}
+
+fn move_left(chamber: &Grid<char>, rock: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    if rock.iter().map(|(x, _)| *x).min() != Some(0) {
+        let moved = rock
+            .iter()
+            .map(|(x, y)| (*x - 1, *y))
+            .collect::<Vec<(_, _)>>();
+        if legal_move(chamber, &moved) {
+            return moved;
+        }
+    }
+    rock.to_vec()
+}
+
+fn move_right(chamber: &Grid<char>, rock: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    if rock.iter().map(|(x, _)| *x).max() != Some(WIDTH - 1) {
+        let moved = rock
+            .iter()
+            .map(|(x, y)| (*x + 1, *y))
+            .collect::<Vec<(_, _)>>();
+        if legal_move(chamber, &moved) {
+            return moved;
+        }
+    }
+    rock.to_vec()
+}
+
+fn move_down(rock: &mut [(usize, usize)]) {
+    for (_, y) in &mut rock.iter_mut() {
+        *y -= 1;
+    }
+}
+
+fn move_up(rock: &mut [(usize, usize)]) {
+    for (_, y) in &mut rock.iter_mut() {
+        *y += 1;
+    }
+}
+
+fn adjust_height(rock: &[(usize, usize)], height: usize) -> Vec<(usize, usize)> {
+    rock.iter()
+        .map(|(x, y)| (*x, *y + height))
+        .collect::<Vec<_>>()
+}
+
+fn legal_move(chamber: &Grid<char>, rock: &[(usize, usize)]) -> bool {
+    // above the top row is empty
+    rock.iter()
+        .all(|(x, y)| matches!(chamber.get((*y, *x)), None | Some('.')))
+}
+
+// The state of the chamber between rocks, the floor is row 0
+#[derive(Clone)]
+struct Tower {
+    chamber: Grid<char>,
+    // the next rock and jet
+    rock: usize,
+    jet: usize,
+}
+
+// The rows from the top of the tower in a cycle key. A rock which falls further
+// than this into a shaft isn't seen by the key, which is why a repeat of the key
+// is only taken as a cycle once the next period repeats it too
+const PROFILE_ROWS: usize = 64;
+
+impl Tower {
+    fn new() -> Self {
+        Self {
+            chamber: Grid::from_elem(1, WIDTH, '-').expect("a one row floor fits"),
+            rock: 0,
+            jet: 0,
+        }
+    }
+
+    // the height of the highest rock
+    fn height(&self) -> usize {
+        self.chamber.nrows() - 1
+    }
+
+    // the next rock and jet with the top PROFILE_ROWS rows, a bit per cell
+    fn key(&self) -> (usize, usize, Vec<u8>) {
+        let top = self.chamber.nrows();
+        let profile = (top.saturating_sub(PROFILE_ROWS)..top)
+            .map(|y| {
+                let row = self.chamber.row(y);
+                (0..WIDTH).fold(0, |bits, x| bits << 1 | u8::from(row[x] != '.'))
+            })
+            .collect();
+        (self.rock, self.jet, profile)
+    }
+
+    // drops the next rock until it comes to rest
+    fn drop_rock(&mut self, jets: &[Dir4], rocks: &[Vec<(usize, usize)>]) {
+        let chamber = &mut self.chamber;
+        let mut rock = adjust_height(&rocks[self.rock], chamber.nrows() - 1 + 4);
+
+        loop {
+            rock = match jets[self.jet] == Dir4::Left {
+                true => move_left(chamber, &rock),
+                false => move_right(chamber, &rock),
+            };
+            move_down(&mut rock);
+
+            self.jet = (self.jet + 1) % jets.len();
+            if !legal_move(chamber, &rock) {
+                move_up(&mut rock);
+                for (x, y) in &rock {
+                    while chamber.nrows() <= *y {
+                        let _ = chamber.push_row(vec!['.'; WIDTH]);
+                    }
+                    chamber[(*y, *x)] = '#';
+                }
+                break;
+            }
+        }
+        self.rock = (self.rock + 1) % rocks.len();
+    }
+}
+
+/// The height of the tower after `n` rocks have fallen, dropping every one of them
+pub fn simulate(jets: &[Dir4], n: usize) -> usize {
+    let rocks = get_rocks(2);
+    let mut tower = Tower::new();
+    for _ in 0..n {
+        tower.drop_rock(jets, &rocks);
+    }
+    tower.height()
+}
+
+/// The height of the tower after `n` rocks have fallen, extrapolated once it's cycling
+///
+/// A repeat of (rock, jet, top rows) is a candidate cycle, confirmed when the next
+/// period grows the tower row for row as the candidate did and ends on the same key.
+/// Without a confirmed cycle every rock is dropped, as in [`simulate`]
+pub fn solve(jets: &[Dir4], n: usize) -> Result<usize, PuzzleError> {
+    let rocks = get_rocks(2);
+    let mut tower = Tower::new();
+    let mut heights = vec![tower.height()];
+    let mut seen = HashMap::new();
+    // the step a key repeated at and the cycle it's a candidate for
+    let mut candidate: Option<(usize, Cycle)> = None;
+
+    for i in 0..n {
+        let key = tower.key();
+        match candidate {
+            Some((repeat, cycle)) if i == repeat + cycle.period => {
+                let grows = |from: usize| {
+                    heights[from..=from + cycle.period]
+                        .windows(2)
+                        .map(|w| w[1] - w[0])
+                        .collect::<Vec<_>>()
+                };
+                candidate = None;
+                if seen.get(&key) == Some(&repeat) && grows(cycle.start) == grows(repeat) {
+                    let cycle = Cycle {
+                        start: repeat,
+                        period: cycle.period,
+                    };
+                    return cycle.extrapolate(&heights, n).ok_or_else(|| {
+                        PuzzleError::overflow(format_args!("the height after {n} rocks"))
+                    });
+                }
+            }
+            Some(_) => {}
+            None => {
+                if let Some(&start) = seen.get(&key) {
+                    let period = i - start;
+                    candidate = Some((i, Cycle { start, period }));
+                }
+            }
+        }
+        seen.insert(key, i);
+        tower.drop_rock(jets, &rocks);
+        heights.push(tower.height());
+    }
+    Ok(tower.height())
+}
+
+/// The height of the tower after 2022 rocks
+pub fn part1(jets: &[Dir4]) -> Result<usize, PuzzleError> {
+    Ok(simulate(jets, 2022))
+}
+
+/// The height of the tower after 1000000000000 rocks
+pub fn part2(jets: &[Dir4]) -> Result<usize, PuzzleError> {
+    solve(jets, 1000000000000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+
+    type Input = Vec<Dir4>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_data(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day17 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "jets",
+        "10091",
+        "the length of the jet pattern",
+    )];
+    const SMALL: &'static [&'static str] = &["jets=200"];
+
+    // Jets pushing left or right at random, every pattern is answered even one
+    // which leaves a shaft open
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let jets = at_least("jets", sizes.get("jets")?, 1)?;
+        Ok(vec![(0..jets).map(|_| *rng.choose(&['<', '>'])).collect()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day17);
+
+    // pushed left by every jet the rocks never reach the right of the chamber, the
+    // shaft stays open to the floor and part 2 still finds the cycle
+    #[test]
+    fn open_shaft() -> Result<(), PuzzleError> {
+        let jets = get_data(&["<".to_string()])?;
+        assert_eq!(part1(&jets)?, 4448);
+        assert!(part2(&jets).is_ok());
+        Ok(())
+    }
+
+    // the extrapolation agrees with the simulation, including a generated pattern whose
+    // shaft is open deep below the top rows of the key
+    #[test]
+    fn extrapolated() -> Result<(), PuzzleError> {
+        let example = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>".to_string();
+        let generated = general::generate::generate::<Day17>(17382647496233710639, &["jets=200"])?;
+        for lines in [
+            vec![example],
+            vec!["<".to_string()],
+            vec![">".to_string()],
+            generated,
+        ] {
+            let jets = get_data(&lines)?;
+            for n in [0, 1, 10, 100, 2022, 5000] {
+                assert_eq!(solve(&jets, n)?, simulate(&jets, n), "{n} rocks");
+            }
+            assert!(part2(&jets).is_ok());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! Day 18: Boiling Boulders
+use general::generate::{at_least, Generate, Rng};
+use general::geom::{BoundingBox3, Point3};
+use general::graph::bfs;
+use general::{parse_lines, trim_split_on, Param, Params, PuzzleError, Solution};
+use std::collections::HashSet;
+
+/// A 1x1x1 cube of the droplet
+pub type Cube = Point3<i32>;
+
+/// Parses the cubes, one per line, ex. "2,2,2" => (2, 2, 2)
+///
+/// the coordinates are i16 so the neighbors of a cube can't overflow
+pub fn get_data(data: &[String]) -> Result<Vec<Cube>, PuzzleError> {
+    parse_lines(data, |line| match trim_split_on::<i16>(line, ',')?[..] {
+        [x, y, z] => Ok(Cube::new(x.into(), y.into(), z.into())),
+        _ => Err(PuzzleError::parse(line, line, "a cube \"x,y,z\"")),
+    })
+}
+
+/// The surface area of the droplet, each pair of adjacent cubes hides a face of both
+pub fn solve1(cubes: &[Cube]) -> usize {
+    let mut faces = 6 * cubes.len();
+
+    for i in 0..cubes.len() {
+        for c in cubes.iter().skip(i + 1) {
+            if cubes[i].manhattan(c) == 1 {
+                faces -= 2;
+            }
+        }
+    }
+    faces
+}
+
+/// The surface area including the air pockets trapped inside the droplet
+pub fn part1(cubes: &[Cube]) -> Result<usize, PuzzleError> {
+    Ok(solve1(cubes))
+}
+
+// true if the air at pos is connected to the outside of the droplet
+//
+// out and visited cache the air found to be outside and inside
+fn interior(
+    pos: Cube,
+    cubes: &HashSet<Cube>,
+    visited: &mut HashSet<Cube>,
+    out: &mut HashSet<Cube>,
+    bbox: &BoundingBox3<i32>,
+) -> bool {
+    if out.contains(&pos) {
+        return true;
+    }
+    if visited.contains(&pos) || cubes.contains(&pos) {
+        return false;
+    }
+
+    // flood fill the air until leaving the bounding box
+    let search = bfs(
+        [pos],
+        |c| {
+            c.neighbors6()
+                .into_iter()
+                .filter(|n| !cubes.contains(n))
+                .collect::<Vec<_>>()
+        },
+        |c| !bbox.contains(c),
+    );
+
+    let seen = search.dist.into_keys();
+    match search.target {
+        Some(_) => {
+            out.extend(seen);
+            true
+        }
+        None => {
+            visited.extend(seen);
+            false
+        }
+    }
+}
+
+/// The exterior surface area, the faces next to air connected to the outside of the droplet
+pub fn solve2(cubes: &[Cube]) -> usize {
+    let Some(bbox) = BoundingBox3::from_points(cubes) else {
+        return 0;
+    };
+    let droplet = cubes.iter().copied().collect::<HashSet<_>>();
+
+    let mut out = HashSet::new();
+    let mut visited = HashSet::new();
+
+    cubes
+        .iter()
+        .map(|c| {
+            c.neighbors6()
+                .into_iter()
+                .filter(|pos| interior(*pos, &droplet, &mut out, &mut visited, &bbox))
+                .count()
+        })
+        .sum()
+}
+
+/// The exterior surface area of the droplet
+pub fn part2(cubes: &[Cube]) -> Result<usize, PuzzleError> {
+    Ok(solve2(cubes))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Boiling Boulders";
+
+    type Input = Vec<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_data(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day18 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("cubes", "2000", "the number of cubes"),
+        Param::new::<usize>(
+            "size",
+            "20",
+            "the cubes are within 0..size on each axis, at most 100",
+        ),
+    ];
+    const SMALL: &'static [&'static str] = &["cubes=300", "size=10"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let cubes = at_least("cubes", sizes.get("cubes")?, 1)?;
+        let size = at_least("size", sizes.get("size")?, 1)?;
+        if size > 100 || cubes > size.pow(3) {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "cubes={cubes}, size={size}: the size is at most 100 and holds at most size^3 cubes"
+            )));
+        }
+        // the cubes closest to the center, give or take, so the droplet is a
+        // rough ball with air pockets near its surface
+        let center = (size - 1) as f64 / 2.0;
+        let mut positions = (0..size.pow(3))
+            .map(|p| {
+                let (x, y, z) = (p % size, p / size % size, p / size / size);
+                let d = [x, y, z]
+                    .iter()
+                    .map(|&v| (v as f64 - center).powi(2))
+                    .sum::<f64>();
+                let jitter = rng.range(0..=size as i64) as f64 / 4.0;
+                (d.sqrt() + jitter, (x, y, z))
+            })
+            .collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(positions[..cubes]
+            .iter()
+            .map(|(_, (x, y, z))| format!("{x},{y},{z}"))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day18);
+}
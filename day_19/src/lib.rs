@@ -0,0 +1,521 @@
+//! Day 19: Not Enough Minerals
+//!
+// This is just an implementation of: https://github.com/betaveros/advent-of-code-2022/blob/main/p19.noul
+//
+use general::generate::{at_least, Generate, Rng};
+use general::params::List;
+use general::parse::ints;
+use general::{parse_lines, Param, Params, PuzzleError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::panic;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The 7 numbers of a blueprint: its id, the ore robot's ore cost, the clay robot's ore cost,
+/// the obsidian robot's ore and clay costs and the geode robot's ore and obsidian costs
+pub type Blueprint = Vec<usize>;
+
+/// Parses the blueprints, one per line,
+/// ex. Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. ...
+pub fn get_data(data: &[String]) -> Result<Vec<Blueprint>, PuzzleError> {
+    parse_lines(data, |line| {
+        let nums = ints::<usize>(line)?;
+        match nums.len() {
+            7 => Ok(nums),
+            _ => Err(PuzzleError::parse(line, line, "a blueprint of 7 numbers")),
+        }
+    })
+}
+
+// An upper bound on the geodes opened in the minutes left: each kind of robot spends
+// its own copy of the ore and one of each kind which is affordable is built every minute,
+// so there are never fewer robots or resources than in a real plan. Unlike the bound of
+// a new geode robot every minute it prunes a blueprint whose geode robots are out of reach
+fn optimistic(minute: usize, costs: &[[usize; 4]], resources: &[usize], bots: &[usize]) -> usize {
+    let mut ore = [resources[0]; 4];
+    let (mut clay, mut obsidian, mut geodes) = (resources[1], resources[2], resources[3]);
+    let mut bots = [bots[0], bots[1], bots[2], bots[3]];
+    for _ in 0..minute {
+        let affordable: [bool; 4] = std::array::from_fn(|i| {
+            ore[i] >= costs[i][0] && clay >= costs[i][1] && obsidian >= costs[i][2]
+        });
+        for pool in &mut ore {
+            *pool += bots[0];
+        }
+        (clay, obsidian, geodes) = (clay + bots[1], obsidian + bots[2], geodes + bots[3]);
+        for i in (0..4).filter(|i| affordable[*i]) {
+            ore[i] -= costs[i][0];
+            clay -= costs[i][1];
+            obsidian -= costs[i][2];
+            bots[i] += 1;
+        }
+    }
+    geodes
+}
+
+/// The most geodes which can be opened in the minutes left, at least `ans`
+#[rustfmt::skip]
+pub fn dfs(
+    minute: usize,
+    max_ore_cost: usize,
+    costs: &[[usize;4]],
+    resources: &[usize],
+    bots: &[usize],
+    ans: usize,
+) -> usize {
+
+    let ans_idle = resources[3] + bots[3] * minute;
+    let mut ans = if ans_idle > ans { ans_idle } else { ans };
+
+    if minute > 0 {
+        let ans_opti = ans_idle + (minute * (minute - 1) / 2);
+        if ans_opti <= ans || optimistic(minute, costs, resources, bots) <= ans {
+            return ans;
+        }
+    }
+
+    let turns_to_do = |cost: &[usize]| -> Option<usize> {
+        // create tuple (resource, bot, cost)
+        let ts = resources.iter()
+            .zip(bots.iter())
+            .zip(cost.iter())
+            .map(|((r, b), c)| {
+                if r >= c {
+                    Some(0)
+                } else if *b > 0 {
+                    Some((c - r).div_ceil(*b))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match ts.contains(&None) {
+            true => None,
+            false => *ts.iter().max().unwrap()
+        }
+    };
+
+    for (i, c) in costs.iter().enumerate() {
+        //                   >= max(ore_bot_cost, clay_bot_cost, obs_bot_ore_cost, geode_bot_ore_cost)
+        if i == 0 && bots[i] >= max_ore_cost { continue; }
+
+        //                   >= obs_bot_clay_cost
+        if i == 1 && bots[i] >= costs[2][1] { continue; }
+
+        //                   >= geode_bot_obs_cost
+        if i == 2 && bots[i] >= costs[3][2] { continue; }
+
+        // the stock and the robots already cover spending the most every minute left
+        let max_spend = [max_ore_cost, costs[2][1], costs[3][2]];
+        if i < 3 && resources[i] + bots[i] * minute >= max_spend[i].saturating_mul(minute) { continue; }
+
+        if let Some(t) = turns_to_do(c) {
+            if t < minute {
+                let resources_adj = (0..bots.len())
+                    .map(|j| resources[j] + (t + 1) * bots[j] - c[j])
+                    .collect::<Vec<_>>();
+
+                let mut bots_adj = bots.to_vec();
+                bots_adj[i] += 1;
+
+                ans = dfs(minute - t - 1, max_ore_cost, costs, &resources_adj, &bots_adj, ans);
+            }
+        }
+    }
+    ans
+}
+
+// The costs of the robots ordered by bot id, each as (ore, clay, obsidian, geodes)
+fn robot_costs(blueprint: &[usize]) -> [[usize; 4]; 4] {
+    let (ore_bot_cost, clay_bot_cost, obs_bot_ore_cost, obs_bot_clay_cost) =
+        (blueprint[1], blueprint[2], blueprint[3], blueprint[4]);
+    let (geode_bot_ore_cost, geode_bot_obs_cost) = (blueprint[5], blueprint[6]);
+    [
+        [ore_bot_cost, 0, 0, 0],
+        [clay_bot_cost, 0, 0, 0],
+        [obs_bot_ore_cost, obs_bot_clay_cost, 0, 0],
+        [geode_bot_ore_cost, 0, geode_bot_obs_cost, 0],
+    ]
+}
+
+/// The most geodes a blueprint can open in `cycles` minutes
+pub fn score(blueprint: &[usize], cycles: usize) -> usize {
+    let costs = robot_costs(blueprint);
+    let max_ore_cost = costs.iter().map(|c| c[0]).max().unwrap_or(0);
+
+    let resources = vec![0, 0, 0, 0];
+    let bots = vec![1, 0, 0, 0];
+    let ans = 0;
+
+    dfs(cycles, max_ore_cost, &costs, &resources, &bots, ans)
+}
+
+// The memoized search, the most geodes the geode robots still to be built open from a
+// state. A geode robot is credited with all it will open when it's built, so the state
+// is the minutes left, the ore, clay and obsidian robots and their resources, each capped
+// at the most the robots built in the minutes left could spend
+struct Memo {
+    costs: [[usize; 4]; 4],
+    max_spend: [usize; 3],
+    table: HashMap<(usize, [usize; 3], [usize; 3]), usize>,
+}
+
+impl Memo {
+    fn geodes(&mut self, minute: usize, bots: [usize; 3], resources: [usize; 3]) -> usize {
+        let resources: [usize; 3] =
+            std::array::from_fn(|j| resources[j].min(self.max_spend[j].saturating_mul(minute)));
+        if let Some(&geodes) = self.table.get(&(minute, bots, resources)) {
+            return geodes;
+        }
+
+        let mut best = 0;
+        for (i, cost) in self.costs.into_iter().enumerate() {
+            // a robot more than the most which can be spent in a minute is no use
+            if i < 3 && bots[i] >= self.max_spend[i] {
+                continue;
+            }
+            // the minutes waiting for the robots to collect the cost, a robot built
+            // in the last minute opens nothing
+            let wait = (0..3).try_fold(0, |wait: usize, j| match resources[j] >= cost[j] {
+                true => Some(wait),
+                false if bots[j] > 0 => Some(wait.max((cost[j] - resources[j]).div_ceil(bots[j]))),
+                false => None,
+            });
+            let Some(t) = wait.filter(|t| t + 1 < minute) else {
+                continue;
+            };
+            let left = minute - t - 1;
+            let after = std::array::from_fn(|j| resources[j] + (t + 1) * bots[j] - cost[j]);
+            let geodes = match i {
+                3 => left + self.geodes(left, bots, after),
+                _ => {
+                    let mut bots = bots;
+                    bots[i] += 1;
+                    self.geodes(left, bots, after)
+                }
+            };
+            best = best.max(geodes);
+        }
+        self.table.insert((minute, bots, resources), best);
+        best
+    }
+}
+
+/// The most geodes a blueprint can open in `cycles` minutes, from a memoized search
+/// of the states rather than the branch and bound of `score`
+pub fn score_memo(blueprint: &[usize], cycles: usize) -> usize {
+    let costs = robot_costs(blueprint);
+    let max_ore_cost = costs.iter().map(|c| c[0]).max().unwrap_or(0);
+    let mut memo = Memo {
+        costs,
+        max_spend: [max_ore_cost, costs[2][1], costs[3][2]],
+        table: HashMap::new(),
+    };
+    memo.geodes(cycles, [1, 0, 0], [0, 0, 0])
+}
+
+/// How the most geodes of a blueprint are searched for, the strategy parameter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// "bnb", the depth first branch and bound of `dfs`
+    #[default]
+    BranchAndBound,
+    /// "memo", the memoized search of `score_memo` with the blueprints in parallel
+    Memo,
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bnb" => Ok(Self::BranchAndBound),
+            "memo" => Ok(Self::Memo),
+            _ => Err(format!("unknown strategy {s:?}, expected bnb or memo")),
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BranchAndBound => write!(f, "bnb"),
+            Self::Memo => write!(f, "memo"),
+        }
+    }
+}
+
+impl Strategy {
+    /// The most geodes each of the blueprints can open in `cycles` minutes
+    pub fn scores(self, blueprints: &[&Blueprint], cycles: usize) -> Vec<usize> {
+        match self {
+            Self::BranchAndBound => blueprints.iter().map(|v| score(v, cycles)).collect(),
+            // no more threads than cores, each taking the next blueprint left until
+            // none are, and a panic in one is resumed here
+            Self::Memo => {
+                let cores = thread::available_parallelism().map_or(1, |n| n.get());
+                let next = AtomicUsize::new(0);
+                let mut scores = vec![0; blueprints.len()];
+                thread::scope(|scope| {
+                    let threads = (0..cores.min(blueprints.len()))
+                        .map(|_| {
+                            scope.spawn(|| {
+                                let mut scored = vec![];
+                                loop {
+                                    let i = next.fetch_add(1, Ordering::Relaxed);
+                                    match blueprints.get(i) {
+                                        Some(v) => scored.push((i, score_memo(v, cycles))),
+                                        None => return scored,
+                                    }
+                                }
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    for t in threads {
+                        let scored = t.join().unwrap_or_else(|e| panic::resume_unwind(e));
+                        for (i, score) in scored {
+                            scores[i] = score;
+                        }
+                    }
+                });
+                scores
+            }
+        }
+    }
+}
+
+/// The sum of the quality levels, each blueprint's id times its geodes in 24 minutes
+pub fn part1(input: &Blueprints) -> Result<usize, PuzzleError> {
+    let blueprints = input.blueprints.iter().collect::<Vec<_>>();
+    let scores = input.strategy.scores(&blueprints, 24);
+    blueprints
+        .iter()
+        .zip(scores)
+        .try_fold(0usize, |sum, (v, geodes)| {
+            sum.checked_add(v[0].checked_mul(geodes)?)
+        })
+        .ok_or_else(|| PuzzleError::overflow("the sum of the quality levels"))
+}
+
+/// The product of the geodes in 32 minutes of the blueprints with the `ids`
+pub fn part2(input: &Blueprints) -> Result<usize, PuzzleError> {
+    let mut blueprints = vec![];
+    for id in &input.ids {
+        let v = input
+            .blueprints
+            .iter()
+            .find(|v| v[0] == *id)
+            .ok_or_else(|| {
+                PuzzleError::InvalidParameter(format!("there's no blueprint {id} for part 2"))
+            })?;
+        blueprints.push(v);
+    }
+    input
+        .strategy
+        .scores(&blueprints, 32)
+        .into_iter()
+        .try_fold(1usize, |product, geodes| product.checked_mul(geodes))
+        .ok_or_else(|| PuzzleError::overflow("the product of the geodes"))
+}
+
+/// The blueprints, the ids of those part 2 scores and the search strategy
+pub struct Blueprints {
+    pub blueprints: Vec<Blueprint>,
+    pub ids: Vec<usize>,
+    pub strategy: Strategy,
+}
+
+/// Parses the blueprints with the part 2 ids and the strategy of the parameters
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Blueprints, PuzzleError> {
+    let List(ids) = params.get("blueprints")?;
+    Ok(Blueprints {
+        blueprints: get_data(puzzle_lines)?,
+        ids,
+        strategy: params.get("strategy")?,
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Not Enough Minerals";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<List<usize>>(
+            "blueprints",
+            "1,2,3",
+            "the blueprint ids part 2 multiplies the geodes of",
+        ),
+        Param::new::<Strategy>(
+            "strategy",
+            "bnb",
+            "the search, bnb (branch and bound) or memo (memoized, in parallel)",
+        ),
+    ];
+
+    type Input = Blueprints;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day19 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "blueprints",
+        "30",
+        "the number of blueprints, 3 or more for part 2",
+    )];
+    const SMALL: &'static [&'static str] = &["blueprints=3"];
+
+    // Costs in the ranges of the puzzle inputs
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let blueprints = at_least("blueprints", sizes.get("blueprints")?, 3)?;
+        Ok((1..=blueprints)
+            .map(|id| {
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. \
+                     Each geode robot costs {} ore and {} obsidian.",
+                    rng.size(2..=4),
+                    rng.size(2..=4),
+                    rng.size(2..=4),
+                    rng.size(5..=20),
+                    rng.size(2..=4),
+                    rng.size(5..=20),
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use general::params::{param_flags, param_overrides};
+    use general::{solve_file, Part};
+    use std::path::Path;
+
+    general::answer_tests!(Day19);
+
+    // the optimistic bound and the max_spend prune leave the geodes the search found
+    // without them, including for blueprints whose geode robots are out of reach
+    #[test]
+    fn pruned() -> Result<(), PuzzleError> {
+        let blueprints = get_data(&[
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 4 ore. \
+             Each obsidian robot costs 4 ore and 20 clay. Each geode robot costs 4 ore and 20 obsidian."
+                .to_string(),
+            "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 4 ore. \
+             Each obsidian robot costs 3 ore and 19 clay. Each geode robot costs 2 ore and 18 obsidian."
+                .to_string(),
+            "Blueprint 3: Each ore robot costs 3 ore. Each clay robot costs 2 ore. \
+             Each obsidian robot costs 4 ore and 7 clay. Each geode robot costs 3 ore and 12 obsidian."
+                .to_string(),
+            "Blueprint 4: Each ore robot costs 2 ore. Each clay robot costs 3 ore. \
+             Each obsidian robot costs 2 ore and 14 clay. Each geode robot costs 4 ore and 9 obsidian."
+                .to_string(),
+            "Blueprint 5: Each ore robot costs 4 ore. Each clay robot costs 3 ore. \
+             Each obsidian robot costs 2 ore and 5 clay. Each geode robot costs 2 ore and 5 obsidian."
+                .to_string(),
+        ])?;
+        let geodes = |cycles| {
+            blueprints
+                .iter()
+                .map(|v| score(v, cycles))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(geodes(24), [0, 0, 11, 9, 26]);
+        assert_eq!(geodes(32), [4, 15, 61, 57, 109]);
+        Ok(())
+    }
+
+    // the memoized search answers the bundled inputs as the branch and bound does
+    #[test]
+    fn strategies() -> Result<(), PuzzleError> {
+        for input in ["input-example", "input-actual"] {
+            let file = Path::new(env!("CARGO_MANIFEST_DIR")).join(input);
+            let solve = |strategy: &str| {
+                let answers = solve_file::<Day19>(Some(&file), &[strategy.into()], Part::All)?;
+                Ok::<_, PuzzleError>((answers.part1, answers.part2))
+            };
+            assert_eq!(solve("strategy=memo")?, solve("strategy=bnb")?, "{input}");
+        }
+        Ok(())
+    }
+
+    // the optimistic bound and the max_spend prune of the branch and bound keep the answers
+    // of the memoized search, which has neither, including for geode robots out of reach
+    #[test]
+    fn pruned_strategies() -> Result<(), PuzzleError> {
+        let blueprints = get_data(&[
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 4 ore. \
+             Each obsidian robot costs 4 ore and 20 clay. Each geode robot costs 4 ore and 20 obsidian."
+                .to_string(),
+            "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 4 ore. \
+             Each obsidian robot costs 3 ore and 19 clay. Each geode robot costs 2 ore and 18 obsidian."
+                .to_string(),
+        ])?;
+        for seed in 0..4 {
+            let lines = general::generate::generate::<Day19>(seed, &["blueprints=4"])?;
+            let generated = get_data(&lines)?;
+            let blueprints = blueprints.iter().chain(&generated).collect::<Vec<_>>();
+            for cycles in [12, 24] {
+                assert_eq!(
+                    Strategy::BranchAndBound.scores(&blueprints, cycles),
+                    Strategy::Memo.scores(&blueprints, cycles),
+                    "seed {seed}, {cycles} minutes"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // --strategy is the shorthand of --param strategy=
+    #[test]
+    fn strategy_flag() -> Result<(), PuzzleError> {
+        let command = clap::Command::new("day_19")
+            .arg(general::param_arg())
+            .args(param_flags(Day19::PARAMS));
+        let args = command
+            .try_get_matches_from(["day_19", "--strategy", "memo"])
+            .unwrap();
+        let overrides = param_overrides(&args, Day19::PARAMS);
+        assert_eq!(overrides, ["strategy=memo"]);
+        let params = Params::new(Day19::PARAMS, &overrides)?;
+        assert_eq!(params.get::<Strategy>("strategy")?, Strategy::Memo);
+        Ok(())
+    }
+
+    #[test]
+    fn generated() -> Result<(), PuzzleError> {
+        let lines = general::generate::generate::<Day19>(7, &["blueprints=6"])?;
+        let blueprints = get_data(&lines)?;
+        let blueprints = blueprints.iter().collect::<Vec<_>>();
+        assert_eq!(
+            Strategy::Memo.scores(&blueprints, 24),
+            Strategy::BranchAndBound.scores(&blueprints, 24)
+        );
+        assert_eq!("memo".parse::<Strategy>(), Ok(Strategy::Memo));
+        assert!("dp".parse::<Strategy>().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 20: Grove Positioning System
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_field, parse_lines, Param, Params, PuzzleError, Solution};
+
+/// Parses the encrypted file, one number per line
+pub fn get_data(data: &[String]) -> Result<Vec<i64>, PuzzleError> {
+    parse_lines(data, |line| parse_field(line, line))
+}
+
+/// Mixes the list multiplied by the decryption `key` for the `rounds`, each number moves as
+/// many places as its value, and sums the numbers at the `offsets` after the 0 times the key
+pub fn solve(list: &[i64], offsets: &[usize], key: i64, rounds: usize) -> Result<i64, PuzzleError> {
+    // create a list of (prev, next) indicies and join the ends
+    // ex. a list of len 4 => [(3, 1), (0, 2), (1, 3), (2, 0)]
+    let len = list.len();
+    let mut clist = (0..len)
+        .map(|i| ((i + len - 1) % len, (i + 1) % len))
+        .collect::<Vec<_>>();
+
+    // will be set to the index where value 0 is encountered
+    let mut zero_index = None;
+
+    for _ in 0..rounds {
+        for (i, v) in list.iter().enumerate() {
+            if *v == 0 {
+                zero_index = Some(i);
+                continue;
+            }
+
+            let n = key
+                .checked_mul(*v)
+                .ok_or_else(|| PuzzleError::overflow(format_args!("{v} times the key")))?;
+            let mut new_index = i;
+            // a number moving len - 1 places is back where it was
+            let places = match list.len() {
+                1 => 0,
+                len => (n.unsigned_abs() % (len as u64 - 1)) as usize,
+            };
+            for _ in 0..places {
+                new_index = match n > 0 {
+                    true => clist[new_index].1,  // right
+                    false => clist[new_index].0, // left
+                };
+            }
+
+            // move one more left so the updates below are the same
+            if n < 0 {
+                new_index = clist[new_index].0;
+            }
+
+            if new_index != i {
+                // update neighbors of clist[i]
+                let (l, r) = (clist[i].0, clist[i].1);
+                (clist[l].1, clist[r].0) = (clist[i].1, clist[i].0);
+
+                // update clist[i]
+                (clist[i].0, clist[i].1) = (new_index, clist[new_index].1);
+
+                // update the new neighbors
+                let (l, r) = (clist[new_index].1, new_index);
+                (clist[l].0, clist[r].1) = (i, i);
+            }
+        }
+    }
+
+    let mut index = zero_index.ok_or_else(|| PuzzleError::NoSolution("no 0 in the list".into()))?;
+
+    // a bit wasteful but helped in viewing the reordered list
+    // build a new list starting at the zero index following to the right
+    let mut olist = vec![];
+    for _ in 0..clist.len() {
+        olist.push(list[index]);
+        index = clist[index].1; // right
+    }
+
+    offsets
+        .iter()
+        .try_fold(0i64, |sum, n| {
+            sum.checked_add(key.checked_mul(olist[*n % olist.len()])?)
+        })
+        .ok_or_else(|| PuzzleError::overflow("the sum of the grove coordinates"))
+}
+
+/// The sum of the grove coordinates after mixing once
+pub fn part1(values: &[i64]) -> Result<i64, PuzzleError> {
+    let (key, rounds) = (1, 1);
+    solve(values, &[1000, 2000, 3000], key, rounds)
+}
+
+/// The sum of the grove coordinates after applying the key and mixing 10 times
+pub fn part2(values: &[i64]) -> Result<i64, PuzzleError> {
+    let (key, rounds) = (811589153, 10);
+    solve(values, &[1000, 2000, 3000], key, rounds)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Grove Positioning System";
+
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_data(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day20 {
+    const SIZES: &'static [Param] = &[Param::new::<usize>(
+        "numbers",
+        "5000",
+        "the length of the file",
+    )];
+    const SMALL: &'static [&'static str] = &["numbers=200"];
+
+    // The numbers are -10000..=10000 with a single 0
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let numbers = at_least("numbers", sizes.get("numbers")?, 1)?;
+        let mut file = (1..numbers)
+            .map(|_| match rng.range(1..=10000) {
+                n if rng.one_in(2) => -n,
+                n => n,
+            })
+            .collect::<Vec<_>>();
+        file.push(0);
+        rng.shuffle(&mut file);
+        Ok(file.iter().map(|n| n.to_string()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day20);
+}
@@ -0,0 +1,320 @@
+//! Day 21: Monkey Math
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_field, Param, Params, PuzzleError, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// The monkeys' jobs, the monkeys which yell a number and those which yell the result
+/// of an operation on two other monkeys' numbers, ex. ["pppw", "+", "sjmn"]
+pub struct Jobs {
+    pub values: HashMap<String, i64>,
+    pub expressions: HashMap<String, Vec<String>>,
+}
+
+/// Parses the monkeys' jobs, one per line, ex. "root: pppw + sjmn" or "dbpl: 5"
+pub fn get_data(data: &[String]) -> Result<Jobs, PuzzleError> {
+    let mut monkey_values = HashMap::new();
+    let mut monkey_exp = HashMap::new();
+
+    for (i, line) in data.iter().enumerate() {
+        // ex. "root: pppw + sjmn" or "dbpl: 5"
+        let (monkey_name, job) = line.split_once(':').ok_or_else(|| {
+            PuzzleError::parse(line, line, "a monkey \"name: job\"").at_line(i + 1)
+        })?;
+        let monkey_name = monkey_name.to_string();
+        match job.split_whitespace().collect::<Vec<&str>>()[..] {
+            [value] => {
+                let value = parse_field::<i64>(line, value).map_err(|e| e.at_line(i + 1))?;
+                monkey_values.insert(monkey_name, value);
+            }
+            [a, op @ ("+" | "-" | "*" | "/"), b] => {
+                monkey_exp.insert(
+                    monkey_name,
+                    vec![a.to_string(), op.to_string(), b.to_string()],
+                );
+            }
+            _ => {
+                let job = job.trim();
+                return Err(
+                    PuzzleError::parse(line, job, "a number or an operation").at_line(i + 1)
+                );
+            }
+        }
+    }
+    Ok(Jobs {
+        values: monkey_values,
+        expressions: monkey_exp,
+    })
+}
+
+/// Evaluates the expressions until the monkey `name` has a value, returns the values found,
+/// an error if it never has one (a monkey is missing or waits on itself) or an operation
+/// overflows or divides by zero
+//
+// we have 2 maps
+//   1. monkey_values: key="monkey name", value=concrete i64 from an evaluation.
+//      (pre-populated with all k,v given to us)
+//   2. monkey_expressions: key="monkey name", value=["some monkey name", "+-*/", "some monkey name"]
+// returns the value for a "monkey name"
+#[rustfmt::skip]
+pub fn solve(
+    name: &str,
+    monkey_values: &HashMap<String, i64>,
+    monkey_expressions: &HashMap<String, Vec<String>>,
+) -> Result<HashMap<String, i64>, PuzzleError> {
+    let mut values = monkey_values.clone();
+    let expressions = monkey_expressions;
+
+    // loop until name evaluates, or a pass evaluates nothing new
+    while !values.contains_key(name) {
+        let evaluated = values.len();
+        for (k, v) in expressions {
+            if !values.contains_key(k) {
+                if let (Some(a), Some(b)) = (values.get(&v[0]), values.get(&v[2])) {
+                    let value = match v[1].as_ref() {
+                        "+" => a.checked_add(*b),
+                        "-" => a.checked_sub(*b),
+                        "*" => a.checked_mul(*b),
+                        "/" if *b == 0 => {
+                            return Err(PuzzleError::NoSolution(format!("{k} divides {a} by 0")))
+                        }
+                        "/" => a.checked_div(*b),
+                        _ => unreachable!("operators are checked by get_data()"),
+                    };
+                    let value = value.ok_or_else(|| {
+                        PuzzleError::overflow(format_args!("{k}: {a} {} {b}", v[1]))
+                    })?;
+                    values.insert(k.to_string(), value);
+                }
+            }
+        }
+        if values.len() == evaluated {
+            return Err(PuzzleError::NoSolution(format!(
+                "{name} never yells, a monkey is missing or waits on itself"
+            )));
+        }
+    }
+    Ok(values)
+}
+
+/// The number the monkey named root yells
+pub fn part1(jobs: &Jobs) -> Result<i64, PuzzleError> {
+    let monkey_values = solve("root", &jobs.values, &jobs.expressions)?;
+    Ok(monkey_values["root"])
+}
+
+/// The number you (humn) yell so that root's two numbers are equal
+pub fn part2(jobs: &Jobs) -> Result<i64, PuzzleError> {
+    let (values, expressions) = (&jobs.values, &jobs.expressions);
+
+    // save a copy
+    let orig_values = values.clone();
+
+    //
+    // this seems wonky with multiple solutions ???
+    // i guess the yell "immediately" means take the lowest
+    //
+
+    // grab the 2 monkey names which "root" depends on
+    let (ma, mb) = match expressions.get("root") {
+        Some(v) => (v[0].to_string(), v[2].to_string()),
+        _ => return Err(PuzzleError::NoSolution("no \"root\" operation".into())),
+    };
+
+    // values "ma,mb" are a function of "humn"
+    // solve part1 to get initial guestimates
+    let mut values = solve("root", values, expressions)?;
+
+    // determine lo, hi ordering
+    let (ma, mb) = if values[&ma] < values[&mb] {
+        (ma, mb)
+    } else {
+        (mb, ma)
+    };
+
+    // take a swag at bounding where to search for "humn" by
+    // increasing values["humn"] looking for ma,mb to cross over
+    let no_balance = || PuzzleError::NoSolution("no value of \"humn\" balances \"root\"".into());
+    let mut humn = *values
+        .get("humn")
+        .ok_or_else(|| PuzzleError::NoSolution("no \"humn\" monkey".into()))?;
+    let mut prev_humn = humn;
+    while values[&ma] < values[&mb] {
+        prev_humn = values["humn"]; // lower bound on exit
+        humn = humn.max(1).checked_mul(2).ok_or_else(no_balance)?; // increase upper bound
+
+        // evaluate the expressions
+        values = orig_values.clone();
+        values.insert("humn".to_string(), humn);
+        values = solve("root", &values, expressions)?;
+    }
+
+    //println!("now searching for humn between {prev_humn} - {humn}");
+
+    let (mut lo, mut hi) = (prev_humn, humn);
+    let mut mid = 0;
+    while values[&ma] != values[&mb] && lo.abs_diff(hi) > 1 {
+        mid = ((lo as i128 + hi as i128) / 2) as i64;
+
+        // evaluate the expressions at `mid`
+        values = orig_values.clone();
+        values.insert("humn".to_string(), mid);
+        values = solve("root", &values, expressions)?;
+
+        //println!("tried {mid} distance between root monkeys {ma} - {mb} = {}", values[&ma] - values[&mb]);
+
+        // pick a side
+        (lo, hi) = match values[&ma] < values[&mb] {
+            true => (mid, hi),
+            false => (lo, mid),
+        };
+    }
+
+    // i'm getting multiple solutions ???
+    let mut solutions = vec![];
+    for wtf in mid.saturating_sub(10)..mid.saturating_add(10) {
+        values = orig_values.clone();
+        values.insert("humn".to_string(), wtf);
+        values = solve("root", &values, expressions)?;
+        if values[&ma] == values[&mb] {
+            solutions.push(wtf);
+        }
+    }
+
+    //println!("solutions = {:?}", solutions);
+
+    solutions.first().copied().ok_or_else(no_balance)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Monkey Math";
+
+    type Input = Jobs;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        get_data(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day21 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>(
+            "chain",
+            "70",
+            "the number of operations between humn and root",
+        ),
+        Param::new::<usize>(
+            "depth",
+            "3",
+            "the deepest operation tree of the other operands",
+        ),
+    ];
+    const SMALL: &'static [&'static str] = &["chain=20"];
+
+    // The operations from humn to root are built up from the number which balances
+    // root, so humn's first number is below it and the search in part 2 finds it.
+    // Divisions are exact at that number and the other operands yell constants
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let chain = at_least("chain", sizes.get("chain")?, 1)?;
+        let depth = sizes.get::<usize>("depth")?;
+        let mut monkeys = Monkeys::default();
+        let answer = rng.range(1_000_000_000..=1_000_000_000_000);
+        monkeys.lines.push(format!("humn: {}", rng.range(1..=5000)));
+
+        // the humn side's number at the answer and how much it changes with humn
+        let (mut name, mut v, mut slope) = ("humn".to_string(), answer, 1.0);
+        for _ in 0..chain {
+            let divisors = (2..=10)
+                .filter(|c| v >= 10_000_000_000 && v % c == 0 && slope / *c as f64 >= 1.0)
+                .collect::<Vec<_>>();
+            let (op, c, humn_left) = match rng.size(0..=4) {
+                4 if !divisors.is_empty() => ('/', *rng.choose(&divisors), true),
+                3 if v <= 1_000_000_000_000 => ('*', rng.range(2..=5), rng.one_in(2)),
+                2 => ('-', 2 * v + rng.range(1..=1000), false),
+                1 => ('-', rng.range(1..=1000), true),
+                _ => ('+', rng.range(1..=1000), rng.one_in(2)),
+            };
+            (v, slope) = match (op, humn_left) {
+                ('+', _) => (v + c, slope),
+                ('-', true) => (v - c, slope),
+                ('-', false) => (c - v, slope),
+                ('*', _) => (v * c, slope * c as f64),
+                _ => (v / c, slope / c as f64),
+            };
+            let other = monkeys.yelling(rng, c, depth);
+            let job = match humn_left {
+                true => format!("{name} {op} {other}"),
+                false => format!("{other} {op} {name}"),
+            };
+            name = monkeys.add(rng, job);
+        }
+        let other = monkeys.yelling(rng, v, depth);
+        monkeys.lines.push(match rng.one_in(2) {
+            true => format!("root: {name} + {other}"),
+            false => format!("root: {other} + {name}"),
+        });
+        rng.shuffle(&mut monkeys.lines);
+        Ok(monkeys.lines)
+    }
+}
+
+// the jobs of a generated input and the names given out
+#[derive(Default)]
+struct Monkeys {
+    lines: Vec<String>,
+    names: HashSet<String>,
+}
+
+impl Monkeys {
+    // a new monkey doing the job, returns its name
+    fn add(&mut self, rng: &mut Rng, job: impl std::fmt::Display) -> String {
+        let name = loop {
+            let name = rng.name(4..=4);
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.lines.push(format!("{name}: {job}"));
+        name
+    }
+
+    // a monkey yelling the positive number `n`, a tree of operations up to `depth` deep
+    fn yelling(&mut self, rng: &mut Rng, n: i64, depth: usize) -> String {
+        if depth == 0 || n < 2 || rng.one_in(3) {
+            return self.add(rng, n);
+        }
+        let (a, op, b) = match (2..=9).find(|d| n % d == 0 && rng.one_in(2)) {
+            Some(d) => (n / d, '*', d),
+            None if rng.one_in(2) => {
+                let b = rng.range(1..=100);
+                (n + b, '-', b)
+            }
+            None => {
+                let a = rng.range(1..=n - 1);
+                (a, '+', n - a)
+            }
+        };
+        let a = self.yelling(rng, a, depth - 1);
+        let b = self.yelling(rng, b, depth - 1);
+        self.add(rng, format!("{a} {op} {b}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    general::answer_tests!(Day21);
+}
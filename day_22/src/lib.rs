@@ -0,0 +1,574 @@
+//! Day 22: Monkey Map
+use general::generate::{at_least, Generate, Rng};
+use general::geom::{Dir4, Point3};
+use general::grid::Grid;
+use general::{paragraphs, parse_field, Param, Params, PuzzleError, Solution};
+use std::collections::{HashMap, VecDeque};
+
+/// A tile of the board, `Void` is off the map, ex. left of a row's first tile
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+/// A step of the path, ex. "10R5" => Forward(10), Right, Forward(5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// The board, its rows padded with Void to the widest, and the path to follow
+#[derive(Clone, Debug)]
+pub struct Notes {
+    pub board: Grid<Tile>,
+    pub path: Vec<Step>,
+}
+
+/// Parses the two paragraphs of the input, the board and the path
+pub fn parse(puzzle_lines: &[String]) -> Result<Notes, PuzzleError> {
+    let mut paragraphs = paragraphs(puzzle_lines);
+    let (_, rows) = paragraphs
+        .next()
+        .ok_or_else(|| PuzzleError::NoSolution("no board in the input".into()))?;
+    let (n, path) = match paragraphs.next() {
+        Some((n, [path, ..])) => (n, path),
+        _ => return Err(PuzzleError::NoSolution("no path in the input".into())),
+    };
+
+    let width = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let padded = rows
+        .iter()
+        .map(|row| format!("{row:width$}"))
+        .collect::<Vec<_>>();
+    let board = Grid::parse_with(&padded, "a tile ' ', '.' or '#'", |c| match c {
+        ' ' => Some(Tile::Void),
+        '.' => Some(Tile::Open),
+        '#' => Some(Tile::Wall),
+        _ => None,
+    })?;
+
+    Ok(Notes {
+        board,
+        path: parse_path(path.trim_end()).map_err(|e| e.at_line(n))?,
+    })
+}
+
+/// Parses the path, numbers of tiles to move forward and the turns 'L' and 'R'
+pub fn parse_path(line: &str) -> Result<Vec<Step>, PuzzleError> {
+    let mut path = vec![];
+    let mut digits = None;
+    for (i, c) in line.char_indices() {
+        if c.is_ascii_digit() {
+            digits.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = digits.take() {
+            path.push(Step::Forward(parse_field(line, &line[start..i])?));
+        }
+        path.push(match c {
+            'L' => Step::Left,
+            'R' => Step::Right,
+            _ => {
+                let found = &line[i..i + c.len_utf8()];
+                return Err(PuzzleError::parse(
+                    line,
+                    found,
+                    "a number of tiles, 'L' or 'R'",
+                ));
+            }
+        });
+    }
+    if let Some(start) = digits {
+        path.push(Step::Forward(parse_field(line, &line[start..])?));
+    }
+    Ok(path)
+}
+
+/// A (row, col) position on the board and the direction faced
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State {
+    pub pos: (usize, usize),
+    pub facing: Dir4,
+}
+
+impl State {
+    /// The final password, 1000 times the row plus 4 times the column (both 1-based)
+    /// plus the facing, 0 for right, 1 down, 2 left and 3 up
+    pub fn password(&self) -> usize {
+        1000 * (self.pos.0 + 1) + 4 * (self.pos.1 + 1) + (self.facing as usize + 3) % 4
+    }
+}
+
+/// Where moving forward off the edge of the map leads
+pub trait Wrap {
+    /// The tile and facing after stepping off the map from `state`
+    fn wrap(&self, board: &Grid<Tile>, state: State) -> State;
+}
+
+/// Wraps around to the other end of the row or column
+pub struct Flat;
+
+impl Wrap for Flat {
+    fn wrap(&self, board: &Grid<Tile>, state: State) -> State {
+        let back = state.facing.opposite().grid_delta();
+        let mut pos = state.pos;
+        while let Some(p) = board.offset(pos, back).filter(|p| board[*p] != Tile::Void) {
+            pos = p;
+        }
+        State { pos, ..state }
+    }
+}
+
+/// The board folded into a cube, each face of the net knows its place in 3-d
+#[derive(Clone, Debug)]
+pub struct Cube {
+    /// the length of an edge of the cube
+    pub size: usize,
+    faces: Vec<Face>,
+    // the face of each (row, col) block of the board
+    blocks: HashMap<(usize, usize), usize>,
+}
+
+// A face of the cube: its block on the board and its outward normal and the directions
+// of the board's right and down on it, unit vectors from the center of the cube
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    block: (usize, usize),
+    normal: Point3<i64>,
+    right: Point3<i64>,
+    down: Point3<i64>,
+}
+
+fn dot(a: Point3<i64>, b: Point3<i64>) -> i64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+impl Face {
+    // the direction of moving in `facing` on the board
+    fn toward(&self, facing: Dir4) -> Point3<i64> {
+        match facing {
+            Dir4::Right => self.right,
+            Dir4::Left => -self.right,
+            Dir4::Down => self.down,
+            Dir4::Up => -self.down,
+        }
+    }
+
+    // the face folded over the edge of this one in the direction of `facing`
+    fn neighbor(&self, facing: Dir4, block: (usize, usize)) -> Face {
+        let (normal, right, down) = (self.normal, self.right, self.down);
+        let (normal, right, down) = match facing {
+            Dir4::Right => (right, -normal, down),
+            Dir4::Left => (-right, normal, down),
+            Dir4::Down => (down, right, -normal),
+            Dir4::Up => (-down, right, normal),
+        };
+        Face {
+            block,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+impl Cube {
+    /// Folds the board, which has to be a net of 6 square faces
+    ///
+    /// Starting from the first face, a face next to a folded one on the board is
+    /// folded over their shared edge, which fixes its normal and orientation in 3-d
+    pub fn fold(board: &Grid<Tile>) -> Result<Self, PuzzleError> {
+        let not_a_net =
+            |why: &str| PuzzleError::NoSolution(format!("the board isn't a cube net, {why}"));
+        let tiles = board.iter().filter(|(_, t)| **t != Tile::Void).count();
+        let size = (1..).find(|n| 6 * n * n >= tiles).unwrap_or(0);
+        if tiles == 0 || 6 * size * size != tiles {
+            return Err(not_a_net(&format!("{tiles} tiles aren't 6 squares")));
+        }
+
+        // the blocks of the board which are faces, the rest have to be Void
+        let mut blocks = vec![];
+        for br in 0..board.nrows().div_ceil(size) {
+            for bc in 0..board.ncols().div_ceil(size) {
+                let tiles = (br * size..(br + 1) * size)
+                    .flat_map(|r| (bc * size..(bc + 1) * size).map(move |c| (r, c)))
+                    .filter(|p| board.get(*p).is_some_and(|t| *t != Tile::Void))
+                    .count();
+                match tiles {
+                    0 => (),
+                    n if n == size * size => blocks.push((br, bc)),
+                    _ => return Err(not_a_net(&format!("a {size}x{size} face is incomplete"))),
+                }
+            }
+        }
+
+        let faces = fold_faces(&blocks).ok_or_else(|| not_a_net("its faces overlap"))?;
+        let blocks = faces
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (f.block, i))
+            .collect();
+        Ok(Cube {
+            size,
+            faces,
+            blocks,
+        })
+    }
+}
+
+// Folds the faces at the (row, col) blocks of a net, None unless they're 6 connected
+// faces which fold to the 6 sides of a cube
+fn fold_faces(blocks: &[(usize, usize)]) -> Option<Vec<Face>> {
+    let first = *blocks.first()?;
+    let mut faces = vec![Face {
+        block: first,
+        normal: Point3::new(0, 0, 1),
+        right: Point3::new(1, 0, 0),
+        down: Point3::new(0, 1, 0),
+    }];
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let face = faces[i];
+        for facing in Dir4::ALL {
+            let (dr, dc) = facing.grid_delta();
+            let (Some(r), Some(c)) = (
+                face.block.0.checked_add_signed(dr),
+                face.block.1.checked_add_signed(dc),
+            ) else {
+                continue;
+            };
+            let block = (r, c);
+            if blocks.contains(&block) && faces.iter().all(|f| f.block != block) {
+                faces.push(face.neighbor(facing, block));
+                queue.push_back(faces.len() - 1);
+            }
+        }
+    }
+    let mut normals = faces.iter().map(|f| f.normal).collect::<Vec<_>>();
+    normals.sort();
+    normals.dedup();
+    (faces.len() == 6 && blocks.len() == 6 && normals.len() == 6).then_some(faces)
+}
+
+impl Wrap for Cube {
+    // Works on the tiles as points on the surface of a cube spanning -size..=size on
+    // each axis, where a tile's center is an odd distance from the center of its face.
+    // Stepping over an edge onto the next face turns the direction of travel into
+    // "into the cube" on it, the opposite of the normal of the face left behind
+    fn wrap(&self, _board: &Grid<Tile>, state: State) -> State {
+        let n = self.size as i64;
+        let (r, c) = state.pos;
+        let from = self.faces[self.blocks[&(r / self.size, c / self.size)]];
+        let (i, j) = ((r % self.size) as i64, (c % self.size) as i64);
+        let point = from.normal * n + from.right * (2 * j + 1 - n) + from.down * (2 * i + 1 - n);
+
+        let toward = from.toward(state.facing);
+        let to = self
+            .faces
+            .iter()
+            .find(|f| f.normal == toward)
+            .expect("a folded cube has a face on every side");
+        let point = point + toward - from.normal;
+        let (i, j) = (
+            (dot(point, to.down) + n - 1) / 2,
+            (dot(point, to.right) + n - 1) / 2,
+        );
+        let facing = Dir4::ALL
+            .into_iter()
+            .find(|d| to.toward(*d) == -from.normal)
+            .expect("the direction into the cube is along the face");
+        State {
+            pos: (
+                to.block.0 * self.size + i as usize,
+                to.block.1 * self.size + j as usize,
+            ),
+            facing,
+        }
+    }
+}
+
+/// The state after one tile forward, unless a wall is in the way
+pub fn forward(board: &Grid<Tile>, wrap: &impl Wrap, state: State) -> State {
+    let next = board
+        .offset(state.pos, state.facing.grid_delta())
+        .filter(|p| board[*p] != Tile::Void)
+        .map_or_else(|| wrap.wrap(board, state), |pos| State { pos, ..state });
+    match board[next.pos] {
+        Tile::Wall => state,
+        _ => next,
+    }
+}
+
+/// Follows the path from the leftmost open tile of the top row facing right
+/// and returns the final password
+pub fn walk(notes: &Notes, wrap: &impl Wrap) -> Result<usize, PuzzleError> {
+    let board = &notes.board;
+    let start = (0..board.ncols())
+        .find(|c| board.get((0, *c)) == Some(&Tile::Open))
+        .ok_or_else(|| PuzzleError::NoSolution("no open tile on the top row".into()))?;
+    let mut state = State {
+        pos: (0, start),
+        facing: Dir4::Right,
+    };
+
+    for step in &notes.path {
+        match step {
+            Step::Left => state.facing = state.facing.turn_left(),
+            Step::Right => state.facing = state.facing.turn_right(),
+            Step::Forward(n) => {
+                // back where it started after k steps, the rest go around again
+                let (start, mut k, mut left) = (state, 0, *n);
+                while left > 0 {
+                    let next = forward(board, wrap, state);
+                    if next == state {
+                        break;
+                    }
+                    (state, k, left) = (next, k + 1, left - 1);
+                    if state == start {
+                        left %= k;
+                    }
+                }
+            }
+        }
+    }
+    Ok(state.password())
+}
+
+/// The password walking the board flat, wrapping around its rows and columns
+pub fn part1(notes: &Notes) -> Result<usize, PuzzleError> {
+    walk(notes, &Flat)
+}
+
+/// The password walking the board folded into a cube
+pub fn part2(notes: &Notes) -> Result<usize, PuzzleError> {
+    walk(notes, &Cube::fold(&notes.board)?)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Map";
+
+    type Input = Notes;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day22 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("size", "50", "the length of an edge of the cube"),
+        Param::new::<usize>("moves", "2000", "the number of moves forward in the path"),
+    ];
+    const SMALL: &'static [&'static str] = &["size=8", "moves=100"];
+
+    // A net grown a face at a time until one folds into a cube, with a wall on
+    // about one tile in ten, and a path turning left or right between moves
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let size = at_least("size", sizes.get("size")?, 1)?;
+        let moves = at_least("moves", sizes.get("moves")?, 1)?;
+
+        let blocks = loop {
+            // grown on a 6x6 block grid from near its center, leaving out a face
+            // which would go off its edges
+            let mut blocks: Vec<(usize, usize)> = vec![(3, 3)];
+            while blocks.len() < 6 {
+                let (r, c) = *rng.choose(&blocks);
+                let (dr, dc) = rng.choose(&Dir4::ALL).grid_delta();
+                let block = (r.checked_add_signed(dr), c.checked_add_signed(dc));
+                if let (Some(r), Some(c)) = block {
+                    if r < 6 && c < 6 && !blocks.contains(&(r, c)) {
+                        blocks.push((r, c));
+                    }
+                }
+            }
+            let top = blocks.iter().map(|b| b.0).min().unwrap_or(0);
+            let left = blocks.iter().map(|b| b.1).min().unwrap_or(0);
+            let mut blocks = blocks
+                .into_iter()
+                .map(|(r, c)| (r - top, c - left))
+                .collect::<Vec<_>>();
+            blocks.sort();
+            if fold_faces(&blocks).is_some() {
+                break blocks;
+            }
+        };
+
+        let rows = (blocks.iter().map(|b| b.0).max().unwrap_or(0) + 1) * size;
+        let mut lines = (0..rows)
+            .map(|r| {
+                let width = blocks
+                    .iter()
+                    .filter(|b| b.0 == r / size)
+                    .map(|b| b.1 + 1)
+                    .max();
+                (0..width.unwrap_or(0) * size)
+                    .map(|c| match blocks.contains(&(r / size, c / size)) {
+                        false => ' ',
+                        true if rng.one_in(10) => '#',
+                        true => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        // the path starts on the first open tile of the top row
+        let start = lines[0].find(|c| c != ' ').unwrap_or(0);
+        lines[0].replace_range(start..start + 1, ".");
+
+        let mut path = String::new();
+        for m in 0..moves {
+            if m > 0 {
+                path.push(*rng.choose(&['L', 'R']));
+            }
+            path.push_str(&rng.size(1..=size).to_string());
+        }
+        lines.extend([String::new(), path]);
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day22,
+        part1_example => (1, "input-example"),
+        part2_example => (2, "input-example"),
+    );
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn path() -> Result<(), Box<dyn Error>> {
+        use Step::*;
+        assert_eq!(
+            parse_path("10R5L0")?,
+            [Forward(10), Right, Forward(5), Left, Forward(0)]
+        );
+        assert!(parse_path("10X5").is_err());
+        Ok(())
+    }
+
+    // the blocks of a net drawn with a '#' for each face
+    fn blocks(net: &[&str]) -> Vec<(usize, usize)> {
+        let mut blocks = vec![];
+        for (r, row) in net.iter().enumerate() {
+            blocks.extend(
+                row.char_indices()
+                    .filter(|(_, c)| *c == '#')
+                    .map(|(c, _)| (r, c)),
+            );
+        }
+        blocks
+    }
+
+    // the 11 nets of a cube
+    const NETS: [&[&str]; 11] = [
+        &["#...", "####", "#..."],
+        &["#...", "####", ".#.."],
+        &["#...", "####", "..#."],
+        &["#...", "####", "...#"],
+        &[".#..", "####", ".#.."],
+        &[".#..", "####", "..#."],
+        &["##..", ".###", ".#.."],
+        &["##..", ".###", "..#."],
+        &["##..", ".###", "...#"],
+        &["##..", ".##.", "..##"],
+        &["###..", "..###"],
+    ];
+
+    #[test]
+    fn nets() {
+        for net in NETS {
+            assert!(fold_faces(&blocks(net)).is_some(), "{net:?}");
+        }
+        // faces which fold on top of each other
+        assert!(fold_faces(&blocks(&["######"])).is_none());
+        assert!(fold_faces(&blocks(&["##", "##", "#.", "#."])).is_none());
+        assert!(fold_faces(&blocks(&["#####", "....#"])).is_none());
+    }
+
+    // Walking straight on an open cube goes around it back to the start after 4
+    // edges, and turning around after a few steps walks back the same way
+    #[test]
+    fn around_the_cube() -> Result<(), Box<dyn Error>> {
+        let n = 3;
+        for net in NETS {
+            let mut board = vec![];
+            for row in net {
+                let row = row
+                    .replace('.', &" ".repeat(n))
+                    .replace('#', &".".repeat(n));
+                board.extend(vec![row; n]);
+            }
+            board.extend(["".into(), "0".into()]);
+            let notes = parse(&board)?;
+            let cube = Cube::fold(&notes.board)?;
+            assert_eq!(cube.size, n);
+
+            let walk =
+                |state, steps| (0..steps).fold(state, |s, _| forward(&notes.board, &cube, s));
+            for (pos, _) in notes.board.iter().filter(|(_, t)| **t == Tile::Open) {
+                for facing in Dir4::ALL {
+                    let start = State { pos, facing };
+                    assert_eq!(walk(start, 4 * n), start, "{net:?} from {start:?}");
+                    let there = walk(start, n + 1);
+                    let back = State {
+                        facing: there.facing.opposite(),
+                        ..there
+                    };
+                    let back = walk(back, n + 1);
+                    assert_eq!((back.pos, back.facing.opposite()), (pos, facing));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn not_a_net() -> Result<(), Box<dyn Error>> {
+        let notes = parse(&lines("......\n\n1"))?;
+        assert!(matches!(part2(&notes), Err(PuzzleError::NoSolution(_))));
+        assert_eq!(part1(&notes)?, 1000 + 4 * 2);
+        Ok(())
+    }
+
+    // a move around the map any number of times finishes
+    #[test]
+    fn long_walk() -> Result<(), Box<dyn Error>> {
+        let notes = parse(&lines(&format!(
+            "  ..\n  ..\n......\n......\n  ..\n  ..\n  ..\n  ..\n\nL{}",
+            usize::MAX
+        )))?;
+        let short = Notes {
+            path: vec![Step::Left, Step::Forward(usize::MAX % 8)],
+            ..notes.clone()
+        };
+        assert_eq!(part1(&notes)?, part1(&short)?);
+        assert_eq!(part2(&notes)?, part2(&short)?);
+        Ok(())
+    }
+}
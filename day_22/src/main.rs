@@ -0,0 +1,6 @@
+use day_22::Day22;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    general::run::<Day22>()
+}
@@ -0,0 +1,258 @@
+//! Day 23: Unstable Diffusion
+use general::generate::{at_least, Generate, Rng};
+use general::geom::{Dir8, Point2};
+use general::grid::Grid;
+use general::{Param, Params, PuzzleError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// An elf's position with y increasing to the north, the top row of the scan is y = 0
+pub type Elf = Point2<i32>;
+
+/// The rounds after which part 2 gives up on the elves ever settling
+pub const MAX_ROUNDS: usize = 100_000;
+
+// the direction an elf proposes to move and the neighbors which have to be empty,
+// considered in this order starting one further along each round
+const PROPOSALS: [(Dir8, [Dir8; 3]); 4] = [
+    (Dir8::N, [Dir8::N, Dir8::NE, Dir8::NW]),
+    (Dir8::S, [Dir8::S, Dir8::SE, Dir8::SW]),
+    (Dir8::W, [Dir8::W, Dir8::NW, Dir8::SW]),
+    (Dir8::E, [Dir8::E, Dir8::NE, Dir8::SE]),
+];
+
+/// The positions of the elves, a sparse set which grows as they spread out
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grove {
+    pub elves: HashSet<Elf>,
+    // the number of rounds run, the first direction considered is PROPOSALS[round % 4]
+    pub round: usize,
+    // print the grove after each round on stderr
+    pub render: bool,
+}
+
+/// Parses the scan, '#' an elf and '.' empty ground, with the render parameter
+pub fn parse(puzzle_lines: &[String], params: &Params) -> Result<Grove, PuzzleError> {
+    let scan = Grid::parse_with(puzzle_lines, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let elves = scan
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((r, c), _)| Elf::new(c as i32, -(r as i32)))
+        .collect();
+    Ok(Grove {
+        elves,
+        round: 0,
+        render: params.get("render")?,
+    })
+}
+
+impl Grove {
+    /// Runs a round of proposals and moves, returns the number of elves which moved
+    pub fn step(&mut self) -> usize {
+        let first = self.round % PROPOSALS.len();
+        let is_empty = |elf: Elf, dirs: &[Dir8]| {
+            dirs.iter()
+                .all(|d| !self.elves.contains(&(elf + d.delta())))
+        };
+
+        // each elf with a neighbor proposes the first direction with nobody in the way
+        let mut proposals = HashMap::new();
+        for &elf in &self.elves {
+            if is_empty(elf, &Dir8::ALL) {
+                continue;
+            }
+            let proposed = (0..PROPOSALS.len())
+                .map(|i| PROPOSALS[(first + i) % PROPOSALS.len()])
+                .find(|(_, checks)| is_empty(elf, checks));
+            if let Some((d, _)) = proposed {
+                proposals
+                    .entry(elf + d.delta())
+                    .or_insert_with(Vec::new)
+                    .push(elf);
+            }
+        }
+
+        // only the elves who are alone in proposing a position move there
+        let mut moved = 0;
+        for (to, from) in proposals {
+            if let [elf] = from[..] {
+                self.elves.remove(&elf);
+                self.elves.insert(to);
+                moved += 1;
+            }
+        }
+
+        self.round += 1;
+        if self.render {
+            eprintln!("== End of Round {} ==\n{self}", self.round);
+        }
+        moved
+    }
+
+    /// The smallest rectangle holding every elf, (min, max) corners
+    pub fn bounds(&self) -> Option<(Elf, Elf)> {
+        let xs = self.elves.iter().map(|e| e.x);
+        let ys = self.elves.iter().map(|e| e.y);
+        Some((
+            Elf::new(xs.clone().min()?, ys.clone().min()?),
+            Elf::new(xs.max()?, ys.max()?),
+        ))
+    }
+
+    /// The empty ground tiles in the smallest rectangle holding every elf
+    pub fn empty_ground(&self) -> usize {
+        self.bounds().map_or(0, |(lo, hi)| {
+            let area = (hi.x - lo.x + 1) as usize * (hi.y - lo.y + 1) as usize;
+            area - self.elves.len()
+        })
+    }
+}
+
+// the smallest rectangle holding every elf, north at the top, ex.
+//   ..#..
+//   ....#
+//   #....
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((lo, hi)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in (lo.y..=hi.y).rev() {
+            let row = (lo.x..=hi.x)
+                .map(|x| match self.elves.contains(&Elf::new(x, y)) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect::<String>();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The empty ground in the elves' rectangle after 10 rounds
+pub fn part1(grove: &Grove) -> Result<usize, PuzzleError> {
+    let mut grove = grove.clone();
+    for _ in 0..10 {
+        grove.step();
+    }
+    Ok(grove.empty_ground())
+}
+
+/// The first round in which no elf moves
+pub fn part2(grove: &Grove) -> Result<usize, PuzzleError> {
+    let mut grove = grove.clone();
+    while grove.round < MAX_ROUNDS {
+        if grove.step() == 0 {
+            return Ok(grove.round);
+        }
+    }
+    Err(PuzzleError::NoSolution(format!(
+        "the elves are still moving after {MAX_ROUNDS} rounds"
+    )))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Unstable Diffusion";
+
+    const PARAMS: &'static [Param] = &[Param::new::<bool>(
+        "render",
+        "false",
+        "print the elves after each round on stderr",
+    )];
+
+    type Input = Grove;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_with(puzzle_lines, &Params::defaults(Self::PARAMS)?)
+    }
+
+    fn parse_with(puzzle_lines: &[String], params: &Params) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day23 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("size", "73", "the width and height of the scan"),
+        Param::new::<usize>("density", "50", "the percentage of tiles with an elf"),
+    ];
+    const SMALL: &'static [&'static str] = &["size=20"];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let size = at_least("size", sizes.get("size")?, 1)?;
+        let density = sizes.get::<u64>("density")?;
+        if density > 100 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "density={density}: must be a percentage, at most 100"
+            )));
+        }
+        Ok((0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.below(100) < density {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day23,
+        part1_example => (1, "input-example"),
+        part2_example => (2, "input-example"),
+    );
+
+    fn grove(text: &str) -> Result<Grove, PuzzleError> {
+        let lines = text.lines().map(String::from).collect::<Vec<_>>();
+        parse(&lines, &Params::defaults(Day23::PARAMS)?)
+    }
+
+    // the small example of the puzzle, three rounds until nobody moves
+    #[test]
+    fn rounds() -> Result<(), Box<dyn Error>> {
+        let mut g = grove(".....\n..##.\n..#..\n.....\n..##.\n.....")?;
+        assert_eq!(g.to_string(), "##\n#.\n..\n##\n");
+        assert_eq!(g.step(), 3);
+        assert_eq!(g.to_string(), "##\n..\n#.\n.#\n#.\n");
+        g.step();
+        assert_eq!(g.to_string(), ".##.\n#...\n...#\n....\n.#..\n");
+        assert_eq!(g.step(), 3);
+        assert_eq!(g.to_string(), "..#..\n....#\n#....\n....#\n.....\n..#..\n");
+        assert_eq!(g.step(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn settled() -> Result<(), Box<dyn Error>> {
+        let g = grove("#.#\n...\n#.#")?;
+        assert_eq!(part1(&g)?, 5);
+        assert_eq!(part2(&g)?, 1);
+        assert_eq!(part2(&grove("")?)?, 1);
+        Ok(())
+    }
+}
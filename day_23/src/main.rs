@@ -0,0 +1,6 @@
+use day_23::Day23;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    general::run::<Day23>()
+}
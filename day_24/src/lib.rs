@@ -0,0 +1,326 @@
+//! Day 24: Blizzard Basin
+use general::generate::{at_least, Generate, Rng};
+use general::geom::Dir4;
+use general::grid::Grid;
+use general::{Param, Params, PuzzleError, Solution};
+use std::collections::HashSet;
+
+/// A (row, column) position in the map, row 0 is the top wall holding the entrance
+pub type Pos = (usize, usize);
+
+/// A tile of the map, a blizzard is marked with the direction it blows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Ground,
+    Blizzard(Dir4),
+}
+
+/// The valley inside the walls and its blizzards
+///
+/// A blizzard wraps around its row (or column), so the blizzards of a row are
+/// where they were at minute 0 shifted by the minute modulo the width, and the
+/// whole valley repeats with a period of lcm(width, height) minutes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valley {
+    // the size of the valley inside the walls
+    pub width: usize,
+    pub height: usize,
+    // the gaps in the top and bottom walls
+    pub entrance: Pos,
+    pub exit: Pos,
+    // the blizzards at minute 0 blowing along a row, [row][col] of the valley
+    east: Vec<Vec<bool>>,
+    west: Vec<Vec<bool>>,
+    // and blowing along a column, [col][row] of the valley
+    north: Vec<Vec<bool>>,
+    south: Vec<Vec<bool>>,
+}
+
+/// Parses the map, walls '#' around the valley of ground '.' and blizzards '^', '>', 'v' and '<',
+/// with a single gap in each of the top and bottom walls
+pub fn parse(puzzle_lines: &[String]) -> Result<Valley, PuzzleError> {
+    let map = Grid::parse_with(
+        puzzle_lines,
+        "one of '#', '.', '^', '>', 'v' or '<'",
+        |c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Ground),
+            '^' => Some(Tile::Blizzard(Dir4::Up)),
+            '>' => Some(Tile::Blizzard(Dir4::Right)),
+            'v' => Some(Tile::Blizzard(Dir4::Down)),
+            '<' => Some(Tile::Blizzard(Dir4::Left)),
+            _ => None,
+        },
+    )?;
+    let (nrows, ncols) = (map.nrows(), map.ncols());
+    if nrows < 3 || ncols < 3 {
+        return Err(PuzzleError::NoSolution(format!(
+            "a {nrows}x{ncols} map has no valley inside its walls"
+        )));
+    }
+
+    // every map char is ASCII, so a column is also a byte offset
+    let error = |(r, c): Pos, expected: &str| {
+        let line = &puzzle_lines[r];
+        PuzzleError::parse(line, &line[c..=c], expected).at_line(r + 1)
+    };
+    let gap = |r: usize| -> Result<Pos, PuzzleError> {
+        let mut gaps = (0..ncols).filter(|&c| map[(r, c)] != Tile::Wall);
+        match (gaps.next(), gaps.next()) {
+            (Some(c), None) if c > 0 && c < ncols - 1 && map[(r, c)] == Tile::Ground => Ok((r, c)),
+            (Some(c), None) => Err(error((r, c), "a gap of ground '.' inside the walls")),
+            (None, _) => Err(error((r, 0), "a gap of ground '.' in the wall")),
+            (Some(_), Some(c)) => Err(error((r, c), "the wall '#' beside a single gap")),
+        }
+    };
+    let (entrance, exit) = (gap(0)?, gap(nrows - 1)?);
+
+    let (width, height) = (ncols - 2, nrows - 2);
+    let mut east = vec![vec![false; width]; height];
+    let mut west = east.clone();
+    let mut north = vec![vec![false; height]; width];
+    let mut south = north.clone();
+    for r in 1..=height {
+        for c in 0..ncols {
+            let wall = c == 0 || c == ncols - 1;
+            match (map[(r, c)], wall) {
+                (Tile::Wall, true) | (Tile::Ground, false) => {}
+                (_, true) => return Err(error((r, c), "the wall '#'")),
+                (Tile::Wall, false) => return Err(error((r, c), "ground or a blizzard")),
+                (Tile::Blizzard(d), false) => match d {
+                    Dir4::Right => east[r - 1][c - 1] = true,
+                    Dir4::Left => west[r - 1][c - 1] = true,
+                    Dir4::Up => north[c - 1][r - 1] = true,
+                    Dir4::Down => south[c - 1][r - 1] = true,
+                },
+            }
+        }
+    }
+
+    Ok(Valley {
+        width,
+        height,
+        entrance,
+        exit,
+        east,
+        west,
+        north,
+        south,
+    })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+impl Valley {
+    /// The minutes after which every blizzard is back where it started
+    pub fn period(&self) -> usize {
+        self.width / gcd(self.width, self.height) * self.height
+    }
+
+    /// True if the position is the entrance, the exit or in the valley without a blizzard at the minute
+    pub fn is_clear(&self, (r, c): Pos, minute: usize) -> bool {
+        if (r, c) == self.entrance || (r, c) == self.exit {
+            return true;
+        }
+        if !(1..=self.height).contains(&r) || !(1..=self.width).contains(&c) {
+            return false;
+        }
+        let (r, c, w, h) = (r - 1, c - 1, self.width, self.height);
+        // the blizzard which would be here now started `minute` steps upwind
+        let (dc, dr) = (minute % w, minute % h);
+        !(self.east[r][(c + w - dc) % w]
+            || self.west[r][(c + dc) % w]
+            || self.south[c][(r + h - dr) % h]
+            || self.north[c][(r + dr) % h])
+    }
+
+    /// The minute of reaching `to` setting out from `from` at `minute`
+    ///
+    /// A breadth first search of the positions reachable minute by minute, moving
+    /// or waiting, where a position reached at a minute equal to an earlier one
+    /// modulo the period leads nowhere new
+    pub fn cross(&self, from: Pos, to: Pos, minute: usize) -> Result<usize, PuzzleError> {
+        let period = self.period();
+        let mut seen = HashSet::from([(from, minute % period)]);
+        let mut positions = vec![from];
+        let mut minute = minute;
+        while !positions.is_empty() {
+            if positions.contains(&to) {
+                return Ok(minute);
+            }
+            minute += 1;
+            let mut next = vec![];
+            for &pos in &positions {
+                let moves = Dir4::ALL.iter().filter_map(|d| {
+                    let (dr, dc) = d.grid_delta();
+                    Some((pos.0.checked_add_signed(dr)?, pos.1.checked_add_signed(dc)?))
+                });
+                for p in moves.chain([pos]) {
+                    if self.is_clear(p, minute) && seen.insert((p, minute % period)) {
+                        next.push(p);
+                    }
+                }
+            }
+            positions = next;
+        }
+        Err(PuzzleError::NoSolution(format!(
+            "no way from {from:?} to {to:?} between the blizzards"
+        )))
+    }
+}
+
+/// The fewest minutes to cross the valley from the entrance to the exit
+pub fn part1(valley: &Valley) -> Result<usize, PuzzleError> {
+    valley.cross(valley.entrance, valley.exit, 0)
+}
+
+/// The fewest minutes to cross the valley, go back for the snacks and cross again
+pub fn part2(valley: &Valley) -> Result<usize, PuzzleError> {
+    let there = valley.cross(valley.entrance, valley.exit, 0)?;
+    let back = valley.cross(valley.exit, valley.entrance, there)?;
+    valley.cross(valley.entrance, valley.exit, back)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Blizzard Basin";
+
+    type Input = Valley;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+impl Generate for Day24 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("width", "120", "the width of the valley inside the walls"),
+        Param::new::<usize>("height", "25", "the height of the valley inside the walls"),
+        Param::new::<usize>(
+            "density",
+            "70",
+            "the percentage of the valley with a blizzard",
+        ),
+    ];
+    const SMALL: &'static [&'static str] = &["width=20", "height=6"];
+
+    // the entrance above the first column and the exit below the last, as in the
+    // puzzle no blizzard blows up or down those columns into a gap in the wall
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let width = at_least("width", sizes.get("width")?, 1)?;
+        let height = at_least("height", sizes.get("height")?, 1)?;
+        let density = sizes.get::<usize>("density")?;
+        if density > 100 {
+            return Err(PuzzleError::InvalidParameter(format!(
+                "density={density}: must be a percentage, at most 100"
+            )));
+        }
+
+        // a valley too crowded to cross is blown again, up to a limit for a density
+        // which never leaves a way through
+        for _ in 0..ATTEMPTS {
+            let lines = blow(rng, width, height, density);
+            if parse(&lines).and_then(|valley| part2(&valley)).is_ok() {
+                return Ok(lines);
+            }
+        }
+        Err(PuzzleError::InvalidParameter(format!(
+            "density={density}: no way through {ATTEMPTS} valleys"
+        )))
+    }
+}
+
+// the valleys generated looking for one which can be crossed
+const ATTEMPTS: usize = 100;
+
+// a map of the valley with a blizzard on about `density` percent of its tiles
+fn blow(rng: &mut Rng, width: usize, height: usize, density: usize) -> Vec<String> {
+    let wall = |gap: usize| {
+        (0..width + 2)
+            .map(|c| if c == gap { '.' } else { '#' })
+            .collect::<String>()
+    };
+    let mut lines = vec![wall(1)];
+    for _ in 0..height {
+        let valley = (1..=width).map(|c| {
+            let blizzards = match c == 1 || c == width {
+                true => &['>', '<'][..],
+                false => &['^', '>', 'v', '<'][..],
+            };
+            match rng.size(1..=100) <= density {
+                true => *rng.choose(blizzards),
+                false => '.',
+            }
+        });
+        lines.push(format!("#{}#", valley.collect::<String>()));
+    }
+    lines.push(wall(width));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day24,
+        part1_example => (1, "input-example"),
+        part2_example => (2, "input-example"),
+    );
+
+    fn valley(text: &str) -> Result<Valley, PuzzleError> {
+        parse(&text.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    // the simple example of the puzzle, the two blizzards meet at minute 3
+    #[test]
+    fn blizzards() -> Result<(), Box<dyn Error>> {
+        let v = valley("#.#####\n#.....#\n#>....#\n#.....#\n#...v.#\n#.....#\n#####.#")?;
+        assert_eq!((v.width, v.height, v.period()), (5, 5, 5));
+        assert_eq!((v.entrance, v.exit), ((0, 1), (6, 5)));
+        assert!(!v.is_clear((2, 1), 0) && !v.is_clear((4, 4), 0));
+        assert!(!v.is_clear((2, 4), 3) && v.is_clear((2, 1), 3));
+        assert!(!v.is_clear((2, 4), 8) && !v.is_clear((3, 4), 4));
+        assert!(v.is_clear((0, 1), 0) && !v.is_clear((0, 2), 0));
+        Ok(())
+    }
+
+    #[test]
+    fn blocked() -> Result<(), Box<dyn Error>> {
+        // a blizzard blowing around a valley one tile wide never moves off it
+        let v = valley("#.#\n#>#\n#.#")?;
+        assert!(matches!(part1(&v), Err(PuzzleError::NoSolution(_))));
+        assert_eq!(part2(&valley("#.#\n#.#\n#.#")?)?, 6);
+        assert!(matches!(
+            valley("#.#\n#.#\n#..#"),
+            Err(PuzzleError::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            valley("#.#\n#.#\n##."),
+            Err(PuzzleError::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            valley("#.#\n^.#\n#.#"),
+            Err(PuzzleError::Parse { line: 2, .. })
+        ));
+        Ok(())
+    }
+}
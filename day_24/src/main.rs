@@ -0,0 +1,6 @@
+use day_24::Day24;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    general::run::<Day24>()
+}
@@ -0,0 +1,304 @@
+//! Day 25: Full of Hot Air
+use general::generate::{at_least, Generate, Rng};
+use general::{parse_field, parse_lines, Param, Params, PuzzleError, Solution};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A number written in SNAFU, balanced base 5 with the digits '=' (-2), '-' (-1), '0', '1'
+/// and '2', most significant first, ex. "1=-0-2" is 1747 and "-" is -1
+///
+/// Every i128 has a SNAFU form and back, so the conversions both ways are lossless,
+/// and arithmetic is on the value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Snafu(i128);
+
+/// The error parsing a SNAFU number
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseSnafuError {
+    Empty,
+    Digit(char),
+    Overflow,
+}
+
+impl fmt::Display for ParseSnafuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no digits"),
+            Self::Digit(c) => write!(f, "{c:?} isn't one of the digits '=', '-', '0', '1' or '2'"),
+            Self::Overflow => write!(f, "too large for an i128"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSnafuError {}
+
+impl Snafu {
+    /// The value of a digit, ex. '=' => -2
+    pub fn digit(c: char) -> Option<i128> {
+        match c {
+            '=' => Some(-2),
+            '-' => Some(-1),
+            '0' => Some(0),
+            '1' => Some(1),
+            '2' => Some(2),
+            _ => None,
+        }
+    }
+
+    /// The sum, None if it overflows an i128
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseSnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseSnafuError::Empty);
+        }
+        // the leading digit outweighs all those after it, so every leading run of the
+        // digits has the sign of the number and a smaller magnitude, which is built up
+        // unsigned so i128::MIN, whose magnitude doesn't fit in an i128, parses too
+        let (mut magnitude, mut negative) = (0u128, false);
+        for c in s.chars() {
+            let d = Self::digit(c).ok_or(ParseSnafuError::Digit(c))?;
+            if magnitude == 0 {
+                negative = d < 0;
+            }
+            magnitude = magnitude
+                .checked_mul(5)
+                .and_then(|m| m.checked_add_signed(if negative { -d } else { d }))
+                .ok_or(ParseSnafuError::Overflow)?;
+        }
+        let value = match negative {
+            true => 0i128.checked_sub_unsigned(magnitude),
+            false => i128::try_from(magnitude).ok(),
+        };
+        value.map(Self).ok_or(ParseSnafuError::Overflow)
+    }
+}
+
+// the digits without leading zeros, "0" for zero
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = vec![];
+        let mut n = self.0;
+        loop {
+            // a remainder of 3 or 4 is the digit 3 - 5 or 4 - 5 carrying one, kept in
+            // euclidean division so i128::MIN doesn't overflow
+            let (q, r) = (n.div_euclid(5), n.rem_euclid(5));
+            let (q, d) = match r {
+                0..=2 => (q, r),
+                _ => (q + 1, r - 5),
+            };
+            digits.push(match d {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                _ => '2',
+            });
+            n = q;
+            if n == 0 {
+                break;
+            }
+        }
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(n: i128) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Snafu> for i128 {
+    fn from(n: Snafu) -> Self {
+        n.0
+    }
+}
+
+// panics on overflow in a debug build as i128 does, see checked_add
+impl Add for Snafu {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Parses the fuel requirements, a SNAFU number per line
+pub fn parse(puzzle_lines: &[String]) -> Result<Vec<Snafu>, PuzzleError> {
+    parse_lines(puzzle_lines, |line| parse_field(line, line))
+}
+
+/// The SNAFU number to enter on Bob's console, the sum of the fuel requirements
+pub fn part1(numbers: &[Snafu]) -> Result<Snafu, PuzzleError> {
+    numbers
+        .iter()
+        .try_fold(Snafu::default(), |sum, &n| sum.checked_add(n))
+        .ok_or_else(|| PuzzleError::overflow("the sum of the fuel requirements"))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Full of Hot Air";
+
+    type Input = Vec<Snafu>;
+    type Answer1 = Snafu;
+    type Answer2 = String;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+        parse(puzzle_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    // the last day has no second puzzle, its star is for finishing the others
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        Err(PuzzleError::NotImplemented(2))
+    }
+}
+
+impl Generate for Day25 {
+    const SIZES: &'static [Param] = &[
+        Param::new::<usize>("numbers", "120", "the number of fuel requirements"),
+        Param::new::<usize>("digits", "20", "the most digits of a fuel requirement"),
+    ];
+
+    // positive numbers as in the puzzle, leading with a '1' or '2'
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError> {
+        let numbers = sizes.get("numbers")?;
+        let digits = at_least("digits", sizes.get("digits")?, 1)?;
+        Ok((0..numbers)
+            .map(|_| {
+                let len = rng.size(1..=digits);
+                let mut number = String::from(*rng.choose(&['1', '2']));
+                for _ in 1..len {
+                    number.push(*rng.choose(&['=', '-', '0', '1', '2']));
+                }
+                number
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::error::Error;
+
+    general::answer_tests!(Day25,
+        part1_example => (1, "input-example"),
+    );
+
+    // the decimal and SNAFU pairs of the puzzle
+    const PAIRS: [(i128, &str); 15] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+
+    #[test]
+    fn pairs() -> Result<(), Box<dyn Error>> {
+        for (n, s) in PAIRS {
+            assert_eq!(Snafu::from(n).to_string(), s);
+            assert_eq!(i128::from(s.parse::<Snafu>()?), n);
+        }
+        assert_eq!(Snafu::from(0).to_string(), "0");
+        assert_eq!(Snafu::from(-1747).to_string(), "-2101=");
+        assert_eq!(i128::from("0012".parse::<Snafu>()?), 7);
+        assert_eq!(
+            PAIRS.iter().map(|&(n, _)| Snafu::from(n)).sum::<Snafu>(),
+            Snafu::from(314_173_722)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Snafu>(), Err(ParseSnafuError::Empty));
+        assert_eq!("1=3".parse::<Snafu>(), Err(ParseSnafuError::Digit('3')));
+        assert_eq!(
+            "1".repeat(60).parse::<Snafu>(),
+            Err(ParseSnafuError::Overflow)
+        );
+        let max = Snafu::from(i128::MAX).to_string();
+        assert_eq!(max.parse::<Snafu>(), Ok(Snafu::from(i128::MAX)));
+        let min = Snafu::from(i128::MIN).to_string();
+        assert_eq!(min.parse::<Snafu>(), Ok(Snafu::from(i128::MIN)));
+        // one past either end, the last digit raised or lowered where it can be
+        let past = |s: &str, from: char, to: char| {
+            let i = s.rfind(from).unwrap();
+            format!("{}{to}{}", &s[..i], &s[i + 1..])
+        };
+        assert_eq!(
+            past(&max, '1', '2').parse::<Snafu>(),
+            Err(ParseSnafuError::Overflow)
+        );
+        assert_eq!(
+            past(&min, '-', '=').parse::<Snafu>(),
+            Err(ParseSnafuError::Overflow)
+        );
+        let numbers = parse(&[max.clone(), "1".into()]).unwrap();
+        assert!(matches!(part1(&numbers), Err(PuzzleError::NoSolution(_))));
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(n in any::<i128>()) {
+            let snafu = Snafu::from(n);
+            prop_assert_eq!(snafu.to_string().parse::<Snafu>(), Ok(snafu));
+            prop_assert_eq!(i128::from(snafu), n);
+        }
+
+        #[test]
+        fn digits(s in "[12][=012-]{0,40}") {
+            // the form of a number is unique without leading zeros
+            prop_assert_eq!(s.parse::<Snafu>().map(|n| n.to_string()), Ok(s));
+        }
+
+        #[test]
+        fn add(a in any::<i64>(), b in any::<i64>()) {
+            let sum = Snafu::from(a as i128) + Snafu::from(b as i128);
+            prop_assert_eq!(i128::from(sum), a as i128 + b as i128);
+            let text = [a, b].map(|n| Snafu::from(n as i128).to_string());
+            let parsed = text.iter().map(|s| s.parse::<Snafu>()).collect::<Result<Vec<_>, _>>()?;
+            prop_assert_eq!(parsed.iter().sum::<Snafu>(), sum);
+        }
+    }
+}
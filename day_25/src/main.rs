@@ -0,0 +1,6 @@
+use day_25::Day25;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    general::run::<Day25>()
+}
@@ -0,0 +1,402 @@
+// Fuzzing each day's parse and solve with proptest
+//
+// Any input, however malformed, has to be answered or rejected with a PuzzleError.
+// A panic (an arithmetic overflow panics in the debug builds the tests use) or a solve
+// which doesn't finish within the time limit is a failure.
+//
+// The inputs are arbitrary printable lines and mutations of the day's input-example,
+// and the inputs its generator makes from any seed have to be answered without an error:
+//   cargo test -p fuzz
+//   PROPTEST_CASES=5000 cargo test -p fuzz day_21
+use general::generate::{generate, solve_params, Generate};
+use general::input::{resolve_input, Input, EXAMPLE};
+use general::{input_params, read_input, solve_with, Params, Part, PuzzleError, Solution};
+use proptest::prelude::*;
+use std::any::Any;
+use std::env;
+use std::fmt;
+use std::panic;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::Duration;
+
+// long enough for a debug build to solve any of the example sized inputs
+pub const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+// the name of the solver threads
+const SOLVER: &str = "fuzz day";
+
+// the cases of each fuzz test unless PROPTEST_CASES is set
+pub const CASES: u32 = 64;
+
+// How a solve failed to return
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(msg) => write!(f, "panicked: {msg}"),
+            Self::Timeout(limit) => write!(f, "didn't finish in {limit:?}"),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(msg) => msg.to_string(),
+            Err(_) => "a non-string panic".to_string(),
+        },
+    }
+}
+
+// The solver threads' panics are reported by check, the default hook only adds noise
+fn quiet_solver_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let solver = thread::current()
+                .name()
+                .is_some_and(|name| name.starts_with(SOLVER));
+            if !solver {
+                default(info)
+            }
+        }));
+    });
+}
+
+// the lines as the day reads them, trimmed for the TRIM_LINES days
+pub fn as_read<S: Solution>(lines: &[String]) -> Vec<String> {
+    match S::TRIM_LINES {
+        true => lines.iter().map(|l| l.trim().to_string()).collect(),
+        false => lines.to_vec(),
+    }
+}
+
+// Parses and solves both parts of the lines on a thread, Ok with the answers or the
+// PuzzleError, a thread still running after `limit` is left behind as the test fails
+pub fn check<S: Solution + 'static>(
+    lines: &[String],
+    params: &Params,
+    limit: Duration,
+) -> Result<Result<(), PuzzleError>, Failure> {
+    quiet_solver_panics();
+    let (lines, params) = (as_read::<S>(lines), params.clone());
+    let (tx, rx) = mpsc::channel();
+    let solver = thread::Builder::new()
+        .name(format!("{SOLVER} {}", S::DAY))
+        .spawn(move || {
+            let solved = solve_with::<S>(&lines, &params, Part::All).map(|_| ());
+            let _ = tx.send(solved);
+        })
+        .map_err(|e| Failure::Panic(format!("couldn't spawn the solver: {e}")))?;
+
+    match rx.recv_timeout(limit) {
+        Ok(solved) => Ok(solved),
+        Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout(limit)),
+        // the sender is dropped without sending when the solver panics
+        Err(RecvTimeoutError::Disconnected) => match solver.join() {
+            Err(payload) => Err(Failure::Panic(panic_message(payload))),
+            Ok(()) => Err(Failure::Panic(
+                "the solver stopped without an answer".into(),
+            )),
+        },
+    }
+}
+
+// The lines of the day's input-example and its parameters from answers.toml
+pub fn example<S: Solution>() -> Result<(Vec<String>, Params), PuzzleError> {
+    let path = resolve_input(S::DAY, &Input::Day(EXAMPLE.into()))
+        .ok_or_else(|| PuzzleError::InvalidParameter(format!("day {} has no {EXAMPLE}", S::DAY)))?;
+    let params = input_params::<S>(Some(&path), &[])?;
+    Ok((read_input::<S>(Some(&path))?, params))
+}
+
+// Solves the day's input generated from the seed with its SMALL sizes, which has to
+// be answered, and checks the seed generates the same input again
+pub fn check_generated<G: Generate + 'static>(seed: u64) -> Result<(), String> {
+    let lines = generate::<G>(seed, G::SMALL).map_err(|e| e.to_string())?;
+    if generate::<G>(seed, G::SMALL).ok().as_ref() != Some(&lines) {
+        return Err(format!("seed {seed} generated a different input"));
+    }
+    let params = solve_params::<G>(G::SMALL).map_err(|e| e.to_string())?;
+    match check::<G>(&lines, &params, TIME_LIMIT) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(err)) => Err(format!("seed {seed}: {err}")),
+        Err(failure) => Err(format!("seed {seed}: {failure}")),
+    }
+}
+
+// the proptest configuration of the fuzz tests, PROPTEST_CASES overrides CASES
+pub fn config() -> ProptestConfig {
+    ProptestConfig {
+        cases: match env::var_os("PROPTEST_CASES") {
+            Some(_) => ProptestConfig::default().cases,
+            None => CASES,
+        },
+        ..ProptestConfig::default()
+    }
+}
+
+// Up to 12 lines of up to 40 printable ASCII characters
+pub fn arbitrary_lines() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec("[ -~]{0,40}", 0..12)
+}
+
+// numbers at the edges of the integer types the days parse
+const NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "-1",
+    "2",
+    "255",
+    "256",
+    "65536",
+    "2147483647",
+    "-2147483648",
+    "4294967296",
+    "9223372036854775807",
+    "-9223372036854775808",
+    "18446744073709551615",
+    "99999999999999999999999",
+];
+
+// An edit of an input, the positions pick a line and a character in it modulo their count
+#[derive(Clone, Debug)]
+pub enum Mutation {
+    DeleteLine(usize),
+    DuplicateLine(usize),
+    SwapLines(usize, usize),
+    Truncate(usize, usize),
+    DeleteChar(usize, usize),
+    InsertChar(usize, usize, char),
+    // replaces the number at a position (or inserts one)
+    Number(usize, usize, &'static str),
+    BlankLine(usize),
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    let index = any::<usize>;
+    prop_oneof![
+        index().prop_map(Mutation::DeleteLine),
+        index().prop_map(Mutation::DuplicateLine),
+        (index(), index()).prop_map(|(a, b)| Mutation::SwapLines(a, b)),
+        (index(), index()).prop_map(|(l, c)| Mutation::Truncate(l, c)),
+        (index(), index()).prop_map(|(l, c)| Mutation::DeleteChar(l, c)),
+        (index(), index(), prop::char::range(' ', '~'))
+            .prop_map(|(l, c, ch)| Mutation::InsertChar(l, c, ch)),
+        (index(), index(), prop::sample::select(NUMBERS))
+            .prop_map(|(l, c, n)| Mutation::Number(l, c, n)),
+        index().prop_map(Mutation::BlankLine),
+    ]
+}
+
+// the char boundary of a line nearest an index
+fn char_at(line: &str, at: usize) -> usize {
+    let bounds = line
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([line.len()])
+        .collect::<Vec<_>>();
+    bounds[at % bounds.len()]
+}
+
+impl Mutation {
+    pub fn apply(&self, lines: &mut Vec<String>) {
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let n = lines.len();
+        match self {
+            Self::DeleteLine(l) => {
+                lines.remove(l % n);
+            }
+            Self::DuplicateLine(l) => {
+                let line = lines[l % n].clone();
+                lines.insert(l % n, line);
+            }
+            Self::SwapLines(a, b) => lines.swap(a % n, b % n),
+            Self::Truncate(l, c) => {
+                let line = &mut lines[l % n];
+                line.truncate(char_at(line, *c));
+            }
+            Self::DeleteChar(l, c) => {
+                let line = &mut lines[l % n];
+                let at = char_at(line, *c);
+                if at < line.len() {
+                    line.remove(at);
+                }
+            }
+            Self::InsertChar(l, c, ch) => {
+                let line = &mut lines[l % n];
+                line.insert(char_at(line, *c), *ch);
+            }
+            Self::Number(l, c, number) => {
+                let line = &mut lines[l % n];
+                let start = char_at(line, *c);
+                let end = line[start..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .map_or(line.len(), |i| start + i);
+                line.replace_range(start..end, number);
+            }
+            Self::BlankLine(l) => lines.insert(l % n, String::new()),
+        }
+    }
+}
+
+// The lines with 1 to 4 mutations
+pub fn mutated(lines: Vec<String>) -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(mutation(), 1..5).prop_map(move |mutations| {
+        let mut lines = lines.clone();
+        for m in &mutations {
+            m.apply(&mut lines);
+        }
+        lines
+    })
+}
+
+// Generates the fuzz tests of days, a module per day with
+//   arbitrary: arbitrary_lines() solved with the default parameters
+//   mutated_example: the mutated input-example solved with its parameters
+//
+//   fuzz_tests!(day_01 => ::day_01::Day01, day_02 => ::day_02::Day02);
+#[macro_export]
+macro_rules! fuzz_tests {
+    ($($name:ident => $day:ty),+ $(,)?) => {
+        $(
+            mod $name {
+                use general::{Params, Solution};
+                use proptest::prelude::*;
+                use $crate::{
+                    arbitrary_lines, check, check_generated, config, example, mutated, TIME_LIMIT,
+                };
+
+                proptest! {
+                    #![proptest_config(config())]
+
+                    #[test]
+                    fn arbitrary(lines in arbitrary_lines()) {
+                        let params = Params::defaults(<$day as Solution>::PARAMS).unwrap();
+                        if let Err(failure) = check::<$day>(&lines, &params, TIME_LIMIT) {
+                            prop_assert!(false, "{failure} on {lines:?}");
+                        }
+                    }
+
+                    #[test]
+                    fn mutated_example(lines in mutated(example::<$day>().unwrap().0)) {
+                        let params = example::<$day>().unwrap().1;
+                        if let Err(failure) = check::<$day>(&lines, &params, TIME_LIMIT) {
+                            prop_assert!(false, "{failure} on {lines:?}");
+                        }
+                    }
+
+                    #[test]
+                    fn generated(seed in any::<u64>()) {
+                        if let Err(failure) = check_generated::<$day>(seed) {
+                            prop_assert!(false, "{failure}");
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fuzz_tests!(
+        day_01 => ::day_01::Day01,
+        day_02 => ::day_02::Day02,
+        day_03 => ::day_03::Day03,
+        day_04 => ::day_04::Day04,
+        day_05 => ::day_05::Day05,
+        day_06 => ::day_06::Day06,
+        day_07 => ::day_07::Day07,
+        day_08 => ::day_08::Day08,
+        day_09 => ::day_09::Day09,
+        day_10 => ::day_10::Day10,
+        day_11 => ::day_11::Day11,
+        day_12 => ::day_12::Day12,
+        day_13 => ::day_13::Day13,
+        day_14 => ::day_14::Day14,
+        day_15 => ::day_15::Day15,
+        day_16 => ::day_16::Day16,
+        day_17 => ::day_17::Day17,
+        day_18 => ::day_18::Day18,
+        day_19 => ::day_19::Day19,
+        day_20 => ::day_20::Day20,
+        day_21 => ::day_21::Day21,
+        day_22 => ::day_22::Day22,
+        day_23 => ::day_23::Day23,
+        day_24 => ::day_24::Day24,
+        day_25 => ::day_25::Day25,
+    );
+
+    #[test]
+    fn mutations() {
+        let mut lines = vec!["move 1 from 2 to 1".to_string(), "é".to_string()];
+        Mutation::Number(2, 12, "-1").apply(&mut lines);
+        Mutation::InsertChar(3, 5, '#').apply(&mut lines);
+        assert_eq!(lines, ["move 1 from -1 to 1", "é#"]);
+    }
+
+    #[test]
+    fn failures() {
+        let params = Params::default();
+        let lines = vec!["1".to_string()];
+        assert!(matches!(
+            check::<Sleepy>(&lines, &params, TIME_LIMIT),
+            Ok(Ok(()))
+        ));
+        let lines = vec!["x".to_string()];
+        assert!(matches!(
+            check::<Sleepy>(&lines, &params, TIME_LIMIT),
+            Ok(Err(PuzzleError::Parse { .. }))
+        ));
+        assert_eq!(
+            check::<Sleepy>(&[], &params, TIME_LIMIT).err(),
+            Some(Failure::Panic("empty".into()))
+        );
+        let lines = vec!["100".to_string()];
+        let limit = Duration::from_millis(10);
+        assert_eq!(
+            check::<Sleepy>(&lines, &params, limit).err(),
+            Some(Failure::Timeout(limit))
+        );
+    }
+
+    // sleeps for the milliseconds of its only line, panics without one
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        const DAY: u8 = 99;
+        const TITLE: &'static str = "Sleepy";
+
+        type Input = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError> {
+            let line = puzzle_lines.first().expect("empty");
+            general::parse_field(line, line)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+            thread::sleep(Duration::from_millis(*input));
+            Ok(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+            Ok(*input)
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use crate::{read_input, Answers, Params, PuzzleError, Solution};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The known answers of a day live in answers.toml beside its inputs, keyed by input file name
+//
+//   [input-example]
+//   part1 = 24000
+//   part2 = 45000
+//
+// an answer is an integer or a string (a multi-line string for a displayed answer) and
+// a part without a known answer can be left out
+//
+// an input which needs parameters other than the day's defaults lists them in `params`
+//
+//   [input-example]
+//   params = { row = 10, bound = 20 }
+//   part1 = 26
+pub const MANIFEST: &str = "answers.toml";
+
+// The known answers for one input
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    // the parameters the answers were found with as "name=value"
+    pub params: Vec<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Manifest {
+    path: PathBuf,
+    inputs: BTreeMap<String, Expected>,
+}
+
+impl Manifest {
+    // Parses the text of a manifest, `path` is only used in messages
+    pub fn parse(path: &Path, text: &str) -> Result<Self, PuzzleError> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|e| toml_error(text, &e))?;
+
+        let mut inputs = BTreeMap::new();
+        for (input, parts) in table {
+            let invalid = |what: &str| {
+                PuzzleError::InvalidParameter(format!("{}: [{input}] {what}", path.display()))
+            };
+            let toml::Value::Table(parts) = parts else {
+                return Err(invalid("isn't a table of part1/part2 answers"));
+            };
+            let mut expected = Expected::default();
+            for (part, answer) in parts {
+                if part == "params" {
+                    let toml::Value::Table(params) = answer else {
+                        return Err(invalid("params isn't a table of name = value"));
+                    };
+                    for (name, value) in params {
+                        let value = param_value(&value)
+                            .ok_or_else(|| invalid(&format!("params has an invalid {name}")))?;
+                        expected.params.push(format!("{name}={value}"));
+                    }
+                    continue;
+                }
+                let answer = match answer {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s,
+                    _ => return Err(invalid(&format!("{part} isn't an integer or a string"))),
+                };
+                match part.as_str() {
+                    "part1" => expected.part1 = Some(answer),
+                    "part2" => expected.part2 = Some(answer),
+                    _ => return Err(invalid(&format!("has an unknown part {part:?}"))),
+                }
+            }
+            inputs.insert(input, expected);
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            inputs,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, PuzzleError> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            PuzzleError::InvalidParameter(format!("can't read {}: {e}", path.display()))
+        })?;
+        Self::parse(path, &text)
+    }
+
+    // The manifest beside an input file and the known answers for the input
+    pub fn for_input(input: &Path) -> Result<(Self, Expected), PuzzleError> {
+        let name = input.file_name().map(|s| s.to_string_lossy().to_string());
+        let Some(name) = name.filter(|_| input.as_os_str() != "-") else {
+            return Err(PuzzleError::InvalidParameter(
+                "checking answers needs an input file".to_string(),
+            ));
+        };
+        let dir = input.parent().filter(|p| !p.as_os_str().is_empty());
+        let manifest = Self::load(&dir.unwrap_or(Path::new(".")).join(MANIFEST))?;
+        match manifest.get(&name) {
+            Some(expected) => {
+                let expected = expected.clone();
+                Ok((manifest, expected))
+            }
+            None => Err(PuzzleError::InvalidParameter(format!(
+                "no known answers for {name} in {}",
+                manifest.path.display()
+            ))),
+        }
+    }
+
+    // The parameters of an input from the answers.toml beside it,
+    // none for standard input, an input without a manifest or without params
+    pub fn params_for(input: &Path) -> Result<Vec<String>, PuzzleError> {
+        let Some(name) = input.file_name().filter(|_| input.as_os_str() != "-") else {
+            return Ok(vec![]);
+        };
+        let dir = input.parent().filter(|p| !p.as_os_str().is_empty());
+        let path = dir.unwrap_or(Path::new(".")).join(MANIFEST);
+        if !path.is_file() {
+            return Ok(vec![]);
+        }
+        let manifest = Self::load(&path)?;
+        Ok(manifest
+            .get(&name.to_string_lossy())
+            .map(|e| e.params.clone())
+            .unwrap_or_default())
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.inputs.get(input)
+    }
+
+    // the inputs and their answers in name order
+    pub fn inputs(&self) -> impl Iterator<Item = (&str, &Expected)> {
+        self.inputs.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+// a parameter value as its argument text, an array is a comma separated list
+fn param_value(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(n) => Some(n.to_string()),
+        toml::Value::Float(f) => Some(f.to_string()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        toml::Value::Array(items) => items
+            .iter()
+            .map(param_value)
+            .collect::<Option<Vec<_>>>()
+            .map(|items| items.join(",")),
+        _ => None,
+    }
+}
+
+// a toml syntax error as a parse error at its position in the manifest
+fn toml_error(text: &str, err: &toml::de::Error) -> PuzzleError {
+    let message = err.message().to_string();
+    let Some(span) = err.span() else {
+        return PuzzleError::parse(text, text, message);
+    };
+    let line = text[..span.start].matches('\n').count();
+    let start = text[..span.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[span.start..]
+        .find('\n')
+        .map_or(text.len(), |i| span.start + i);
+    let found_end = span.end.clamp(span.start, end);
+    PuzzleError::parse(&text[start..end], &text[span.start..found_end], message).at_line(line + 1)
+}
+
+// answers compare by their lines without trailing spaces or surrounding blank lines
+fn normalize(answer: &str) -> Vec<&str> {
+    let lines = answer.lines().map(str::trim_end).collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].to_vec()
+}
+
+// A line diff of an answer, ex.
+//   - 68468
+//   + 68467
+fn diff(expected: &[&str], found: &[&str]) -> String {
+    let mut lines = vec![];
+    for i in 0..expected.len().max(found.len()) {
+        match (expected.get(i), found.get(i)) {
+            (Some(e), Some(f)) if e == f => lines.push(format!("  {e}")),
+            (e, f) => {
+                lines.extend(e.map(|e| format!("- {e}")));
+                lines.extend(f.map(|f| format!("+ {f}")));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+// the part, input and diff of an answer which differs from its known value
+fn mismatch(input: &str, part: u8, expected: &str, found: &str) -> Option<String> {
+    let (expected, found) = (normalize(expected), normalize(found));
+    match expected == found {
+        true => None,
+        false => Some(format!(
+            "part {part} of {input}\n{}",
+            diff(&expected, &found)
+        )),
+    }
+}
+
+// Compares an answer with its known value, a mismatch is a WrongAnswer error
+// with a diff of the expected (-) and found (+) answers
+pub fn check_part(input: &str, part: u8, expected: &str, found: &str) -> Result<(), PuzzleError> {
+    match mismatch(input, part, expected, found) {
+        Some(diff) => Err(PuzzleError::WrongAnswer(diff)),
+        None => Ok(()),
+    }
+}
+
+// Compares the answers of both parts with the known ones,
+// parts without a known answer or which weren't run pass
+pub fn check(input: &str, answers: &Answers, expected: &Expected) -> Result<(), PuzzleError> {
+    let diffs = [1, 2]
+        .into_iter()
+        .filter_map(|part| mismatch(input, part, expected.part(part)?, answers.part(part)?))
+        .collect::<Vec<_>>();
+    match diffs.is_empty() {
+        true => Ok(()),
+        false => Err(PuzzleError::WrongAnswer(diffs.join("\n"))),
+    }
+}
+
+// Solves one part of an input in `dir` and compares it with the answer in the manifest,
+// used by the tests which answer_tests! generates
+pub fn check_input<S: Solution>(dir: &str, input: &str, part: u8) -> Result<(), PuzzleError> {
+    let dir = Path::new(dir);
+    let manifest = Manifest::load(&dir.join(MANIFEST))?;
+    let expected = manifest.get(input);
+    let known = expected.and_then(|e| e.part(part)).ok_or_else(|| {
+        PuzzleError::InvalidParameter(format!(
+            "no known part {part} answer for {input} in {}",
+            manifest.path.display()
+        ))
+    })?;
+
+    let params = Params::new(S::PARAMS, expected.map_or(&[][..], |e| &e.params))?;
+    let parsed = S::parse_with(&read_input::<S>(Some(&dir.join(input)))?, &params)?;
+    let found = match part {
+        1 => S::part1(&parsed)?.to_string(),
+        _ => S::part2(&parsed)?.to_string(),
+    };
+    check_part(input, part, known, &found)
+}
+
+// Checks every known answer in the manifest in `dir` except those of the `skip` inputs
+pub fn check_manifest<S: Solution>(dir: &str, skip: &[&str]) -> Result<(), PuzzleError> {
+    let manifest = Manifest::load(&Path::new(dir).join(MANIFEST))?;
+    for (input, expected) in manifest.inputs().filter(|(i, _)| !skip.contains(i)) {
+        for part in [1, 2].into_iter().filter(|p| expected.part(*p).is_some()) {
+            check_input::<S>(dir, input, part)?;
+        }
+    }
+    Ok(())
+}
+
+// Generates the answer tests of a day from the answers.toml in its crate directory:
+// part1_example, part1_actual, part2_example and part2_actual for input-example and
+// input-actual, and other_inputs for any other input listed in the manifest
+//
+//   #[cfg(test)]
+//   mod tests {
+//       use super::*;
+//       general::answer_tests!(Day01);
+//   }
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:ty) => {
+        $crate::answer_tests!($day,
+            part1_example => (1, "input-example"),
+            part1_actual => (1, "input-actual"),
+            part2_example => (2, "input-example"),
+            part2_actual => (2, "input-actual"),
+        );
+    };
+    ($day:ty, $($name:ident => ($part:literal, $input:literal)),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let dir = env!("CARGO_MANIFEST_DIR");
+                if let Err(e) = $crate::answers::check_input::<$day>(dir, $input, $part) {
+                    panic!("{e}");
+                }
+            }
+        )+
+
+        #[test]
+        fn other_inputs() {
+            let dir = env!("CARGO_MANIFEST_DIR");
+            if let Err(e) = $crate::answers::check_manifest::<$day>(dir, &[$($input),+]) {
+                panic!("{e}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timings;
+
+    const MANIFEST_TEXT: &str = r#"
+[input-example]
+params = { row = 10, ids = [1, 2] }
+part1 = 24000
+part2 = "CMZ"
+
+[input-screen]
+part2 = '''
+#  #
+ ## '''
+"#;
+
+    fn answers(part1: &str, part2: &str) -> Answers {
+        Answers {
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn parse_and_check() {
+        let manifest = Manifest::parse(Path::new(MANIFEST), MANIFEST_TEXT).unwrap();
+        let example = manifest.get("input-example").unwrap();
+        assert_eq!(example.part(1), Some("24000"));
+        assert_eq!(example.params, ["ids=1,2", "row=10"]);
+        assert!(check("input-example", &answers("24000", "CMZ"), example).is_ok());
+
+        // a displayed answer may have surrounding blank lines and trailing spaces
+        let screen = manifest.get("input-screen").unwrap();
+        assert_eq!(screen.part(1), None);
+        assert!(check("input-screen", &answers("0", "\n#  #\n ##  \n"), screen).is_ok());
+
+        match check("input-example", &answers("24001", "CMZ"), example) {
+            Err(PuzzleError::WrongAnswer(diff)) => {
+                assert_eq!(diff, "part 1 of input-example\n- 24000\n+ 24001")
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_manifests() {
+        let path = Path::new(MANIFEST);
+        match Manifest::parse(path, "[input-example]\npart1 = = 3\n") {
+            Err(PuzzleError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {other:?}"),
+        }
+        assert!(Manifest::parse(path, "[input-example]\npart3 = 3\n").is_err());
+        assert!(Manifest::parse(path, "[input-example]\npart1 = 1.5\n").is_err());
+        assert!(Manifest::parse(path, "input-example = 3\n").is_err());
+        assert!(Manifest::parse(path, "[input-example]\nparams = 3\n").is_err());
+    }
+}
@@ -0,0 +1,355 @@
+use crate::output::Format;
+use crate::params::Params;
+use crate::solution::{run_part, timed};
+use crate::{Part, PuzzleError, Solution};
+use serde_json::json;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+// Summary statistics of the samples of one phase
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    // None for no samples, the p95 is the nearest rank and the stddev is the population's
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let min = *sorted.first()?;
+        let median = match n % 2 {
+            1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+
+        let secs = sorted.iter().map(|d| d.as_secs_f64()).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+        let p95 = sorted[(n * 95).div_ceil(100).max(1) - 1];
+        Some(Self {
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+// The statistics of each phase over the timed iterations
+#[derive(Clone, Debug)]
+pub struct Bench {
+    pub iterations: usize,
+    pub warmup: usize,
+    // the parts which were run
+    pub part: Part,
+    // parse, part1 and part2 in the order of PHASES, a part which wasn't run has no samples
+    pub phases: [Stats; 3],
+}
+
+impl Bench {
+    // the phases which were run and their statistics
+    pub fn phases(&self) -> impl Iterator<Item = (&'static str, &Stats)> {
+        PHASES
+            .into_iter()
+            .zip(&self.phases)
+            .zip([true, self.part.includes(1), self.part.includes(2)])
+            .filter_map(|(phase, ran)| ran.then_some(phase))
+    }
+}
+
+// Parses and solves the selected parts `warmup` times untimed, then `iterations` times
+// timing each phase separately
+pub fn bench<S: Solution>(
+    puzzle_lines: &[String],
+    params: &Params,
+    part: Part,
+    iterations: usize,
+    warmup: usize,
+) -> Result<Bench, PuzzleError> {
+    let mut samples = [vec![], vec![], vec![]];
+    for i in 0..warmup + iterations {
+        let (input, parse) = timed(|| S::parse_with(black_box(puzzle_lines), params))?;
+        let (part1, part1_time) = run_part(part, 1, || S::part1(&input))?;
+        let (part2, part2_time) = run_part(part, 2, || S::part2(&input))?;
+        black_box((part1, part2));
+        if i >= warmup {
+            for (s, d) in samples.iter_mut().zip([parse, part1_time, part2_time]) {
+                s.push(d);
+            }
+        }
+    }
+
+    let stats = |s: &[Duration]| {
+        Stats::from_samples(s).ok_or_else(|| {
+            PuzzleError::InvalidParameter("--bench needs 1 or more iterations".into())
+        })
+    };
+    Ok(Bench {
+        iterations,
+        warmup,
+        part,
+        phases: [
+            stats(&samples[0])?,
+            stats(&samples[1])?,
+            stats(&samples[2])?,
+        ],
+    })
+}
+
+// The medians of earlier runs in a toml file, one table per day and input, ex.
+//   ["day_15/input-actual"]
+//   parse = 52345
+//   part1 = 1205311
+//   part2 = 98012345
+// the values are nanoseconds
+pub struct Baseline {
+    table: toml::Table,
+}
+
+// A phase whose median is slower than the baseline's by more than the threshold
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub median: Duration,
+    // percent slower than the baseline
+    pub slower: f64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} regressed: median {:?} is {:.1}% slower than the baseline {:?}",
+            self.phase, self.median, self.slower, self.baseline
+        )
+    }
+}
+
+// the baseline key of a day's input, ex. "day_15/input-actual"
+pub fn baseline_key(day: u8, input: Option<&Path>) -> String {
+    let name = input
+        .and_then(|p| p.file_name())
+        .map_or("-".into(), |s| s.to_string_lossy());
+    format!("day_{day:02}/{name}")
+}
+
+fn nanos(d: Duration) -> i64 {
+    i64::try_from(d.as_nanos()).unwrap_or(i64::MAX)
+}
+
+impl Baseline {
+    // an empty baseline if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self, PuzzleError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let table = text.parse::<toml::Table>().map_err(|e| {
+            PuzzleError::InvalidParameter(format!("baseline {}: {}", path.display(), e.message()))
+        })?;
+        Ok(Self { table })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), PuzzleError> {
+        Ok(fs::write(path, self.table.to_string())?)
+    }
+
+    // records the medians of the phases a benchmark ran
+    pub fn update(&mut self, key: &str, bench: &Bench) {
+        let medians = self
+            .table
+            .entry(key)
+            .or_insert_with(|| toml::Table::new().into());
+        if let Some(medians) = medians.as_table_mut() {
+            for (phase, stats) in bench.phases() {
+                medians.insert(phase.to_string(), nanos(stats.median).into());
+            }
+        }
+    }
+
+    // the phases slower than the baseline by more than `threshold` percent,
+    // phases without a baseline are skipped
+    pub fn regressions(&self, key: &str, bench: &Bench, threshold: f64) -> Vec<Regression> {
+        let Some(medians) = self.table.get(key).and_then(|v| v.as_table()) else {
+            return vec![];
+        };
+        bench
+            .phases()
+            .filter_map(|(phase, stats)| {
+                let base = medians.get(phase)?.as_integer()?;
+                let baseline = Duration::from_nanos(u64::try_from(base).ok()?);
+                let slower = match baseline.is_zero() {
+                    true => 0.0,
+                    false => (stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0,
+                };
+                (slower > threshold).then_some(Regression {
+                    phase,
+                    baseline,
+                    median: stats.median,
+                    slower,
+                })
+            })
+            .collect()
+    }
+}
+
+// Writes a benchmark in the output format
+//   text: a table of the statistics per phase
+//   json: one object with the statistics in nanoseconds
+//   tsv:  a header then a row per phase
+pub fn write_bench<W: Write>(
+    mut out: W,
+    format: Format,
+    title: &str,
+    key: &str,
+    bench: &Bench,
+    regressions: &[Regression],
+) -> io::Result<()> {
+    let rows = bench.phases();
+    match format {
+        Format::Text => {
+            writeln!(
+                out,
+                "{title} ({key}): {} iterations after {} warm-up",
+                bench.iterations, bench.warmup
+            )?;
+            writeln!(
+                out,
+                "{:<6} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                "phase", "min", "median", "mean", "p95", "stddev"
+            )?;
+            for (phase, s) in rows {
+                writeln!(
+                    out,
+                    "{phase:<6} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                    format!("{:.2?}", s.min),
+                    format!("{:.2?}", s.median),
+                    format!("{:.2?}", s.mean),
+                    format!("{:.2?}", s.p95),
+                    format!("{:.2?}", s.stddev),
+                )?;
+            }
+            for r in regressions {
+                writeln!(out, "{r}")?;
+            }
+        }
+        Format::Json => {
+            let mut stats = serde_json::Map::new();
+            for (phase, s) in rows {
+                let phase_stats = json!({
+                    "min": nanos(s.min),
+                    "median": nanos(s.median),
+                    "mean": nanos(s.mean),
+                    "p95": nanos(s.p95),
+                    "stddev": nanos(s.stddev),
+                });
+                stats.insert(phase.to_string(), phase_stats);
+            }
+            let record = json!({
+                "title": title,
+                "key": key,
+                "iterations": bench.iterations,
+                "warmup": bench.warmup,
+                "stats_ns": stats,
+                "regressions": regressions.iter().map(|r| r.phase).collect::<Vec<_>>(),
+            });
+            writeln!(out, "{record}")?;
+        }
+        Format::Tsv => {
+            writeln!(
+                out,
+                "key\tphase\tmin_ns\tmedian_ns\tmean_ns\tp95_ns\tstddev_ns\tregressed"
+            )?;
+            for (phase, s) in rows {
+                let regressed = regressions.iter().any(|r| r.phase == phase);
+                writeln!(
+                    out,
+                    "{key}\t{phase}\t{}\t{}\t{}\t{}\t{}\t{regressed}",
+                    nanos(s.min),
+                    nanos(s.median),
+                    nanos(s.mean),
+                    nanos(s.p95),
+                    nanos(s.stddev),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn statistics() {
+        let samples = (1..=20).rev().map(ms).collect::<Vec<_>>();
+        let s = Stats::from_samples(&samples).unwrap();
+        assert_eq!(
+            (s.min, s.median, s.p95),
+            (ms(1), Duration::from_micros(10500), ms(19))
+        );
+        assert_eq!(s.mean, Duration::from_micros(10500));
+        assert_eq!(s.stddev.as_micros(), 5766);
+        assert_eq!(Stats::from_samples(&[]), None);
+
+        let s = Stats::from_samples(&[ms(3)]).unwrap();
+        assert_eq!((s.median, s.p95, s.stddev), (ms(3), ms(3), Duration::ZERO));
+    }
+
+    #[test]
+    fn regressions() {
+        let stats = |median| Stats {
+            median,
+            ..Stats::default()
+        };
+        let bench = Bench {
+            iterations: 1,
+            warmup: 0,
+            part: Part::All,
+            phases: [stats(ms(10)), stats(ms(20)), stats(ms(30))],
+        };
+        let key = baseline_key(15, Some(Path::new("day_15/input-actual")));
+        assert_eq!(key, "day_15/input-actual");
+
+        let mut baseline = Baseline {
+            table: toml::Table::new(),
+        };
+        assert!(baseline.regressions(&key, &bench, 10.0).is_empty());
+        baseline.update(&key, &bench);
+
+        let slower = Bench {
+            phases: [stats(ms(10)), stats(ms(21)), stats(ms(40))],
+            ..bench
+        };
+        let regressions = baseline.regressions(&key, &slower, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(
+            (regressions[0].phase, regressions[0].slower.round()),
+            ("part2", 33.0)
+        );
+
+        // a saved baseline reads back the same
+        let reloaded = baseline.table.to_string().parse::<toml::Table>().unwrap();
+        assert_eq!(reloaded, baseline.table);
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::hash::Hash;
+
+// A cycle in a sequence of states x0, x1 = step(x0), x2 = step(x1), ...
+//
+// the states from step `start` on repeat every `period` steps, x(start + period) == x(start)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // the earliest step with the same state as step n
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+
+    // Extrapolates a metric which accumulates a fixed amount every period, ex. a height
+    //
+    // values[k] is the metric after k steps and must cover at least start + period steps,
+    // returns None if values is too short or the value doesn't fit M
+    pub fn extrapolate<M: Metric>(&self, values: &[M], n: usize) -> Option<M> {
+        if n < values.len() {
+            return Some(values[n]);
+        }
+        let end = self.start + self.period;
+        let per_period = values.get(end)?.checked_sub(values[self.start])?;
+        let periods = M::try_from((n - self.start) / self.period).ok()?;
+        values[self.equivalent_step(n)].checked_add(periods.checked_mul(per_period)?)
+    }
+}
+
+// The integer types a metric can be extrapolated in, with arithmetic checked for overflow
+pub trait Metric: Copy + TryFrom<usize> {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_metric {
+    ($($t:ty),+) => {$(
+        impl Metric for $t {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        }
+    )+};
+}
+
+impl_metric!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Brent's algorithm https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+//
+// states are compared by their key, None if no cycle is found within `limit` steps
+pub fn brent<S, K, F, KF>(initial: S, mut step: F, mut key: KF, limit: usize) -> Option<Cycle>
+where
+    K: PartialEq,
+    F: FnMut(&S) -> S,
+    KF: FnMut(&S) -> K,
+{
+    // find the period: the hare searches successive powers of two for the tortoise
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(&initial);
+    let mut hare = step(&initial);
+    let mut steps = 1;
+    while key(&hare) != tortoise {
+        if steps >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // find the start: walk two states `period` apart until they meet
+    let mut tortoise = initial;
+    let mut hare = step(&tortoise);
+    for _ in 1..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+// Floyd's tortoise and hare https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+//
+// states are compared by their key, None if no cycle is found within `limit` steps
+pub fn floyd<S, K, F, KF>(initial: S, mut step: F, mut key: KF, limit: usize) -> Option<Cycle>
+where
+    K: PartialEq,
+    F: FnMut(&S) -> S,
+    KF: FnMut(&S) -> K,
+{
+    // the hare moves twice as fast until they meet inside the cycle
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    let mut steps = 1;
+    while key(&tortoise) != key(&hare) {
+        if steps >= limit {
+            return None;
+        }
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+        steps += 1;
+    }
+
+    // the start is as far from the initial state as from the meeting point
+    let mut start = 0;
+    tortoise = initial;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    // the period is the distance around the cycle
+    let mut period = 1;
+    hare = step(&tortoise);
+    while key(&tortoise) != key(&hare) {
+        hare = step(&hare);
+        period += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+// Finds the first repeated key with a HashMap of the keys seen
+//
+// uses memory for every key but steps each state only once,
+// None if no cycle is found within `limit` steps
+pub fn first_repeat<S, K, F, KF>(
+    initial: S,
+    mut step: F,
+    mut key: KF,
+    limit: usize,
+) -> Option<Cycle>
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    KF: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for n in 0..=limit {
+        if let Some(start) = seen.insert(key(&state), n) {
+            return Some(Cycle {
+                start,
+                period: n - start,
+            });
+        }
+        state = step(&state);
+    }
+    None
+}
+
+// The metric of the state after n steps, skipping ahead by whole periods once the
+// keys repeat (the first repeat)
+//
+// the metric must grow by the same amount every period, ex. the height of a tower
+pub fn metric_at<S, K, M, F, KF, MF>(
+    initial: S,
+    n: usize,
+    mut step: F,
+    key: KF,
+    metric: MF,
+) -> Option<M>
+where
+    K: Eq + Hash,
+    M: Metric,
+    F: FnMut(&S) -> S,
+    KF: FnMut(&S) -> K,
+    MF: FnMut(&S) -> M,
+{
+    let step = |s: &S| Ok::<_, Infallible>(step(s));
+    match try_metric_at(initial, n, step, key, metric) {
+        Ok(m) => m,
+        Err(never) => match never {},
+    }
+}
+
+// metric_at with a step which can fail, the first error is returned
+pub fn try_metric_at<S, K, M, E, F, KF, MF>(
+    initial: S,
+    n: usize,
+    mut step: F,
+    mut key: KF,
+    mut metric: MF,
+) -> Result<Option<M>, E>
+where
+    K: Eq + Hash,
+    M: Metric,
+    F: FnMut(&S) -> Result<S, E>,
+    KF: FnMut(&S) -> K,
+    MF: FnMut(&S) -> M,
+{
+    let mut seen = HashMap::new();
+    let mut values = vec![];
+    let mut state = initial;
+    for i in 0..=n {
+        values.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), i) {
+            let cycle = Cycle {
+                start,
+                period: i - start,
+            };
+            return Ok(cycle.extrapolate(&values, n));
+        }
+        if i < n {
+            state = step(&state)?;
+        }
+    }
+    Ok(values.last().copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn step(n: &u64) -> u64 {
+        match *n {
+            5 => 3,
+            n => n + 1,
+        }
+    }
+
+    #[test]
+    fn detect() {
+        let expected = Some(Cycle {
+            start: 3,
+            period: 3,
+        });
+        assert_eq!(brent(0, step, |n| *n, 100), expected);
+        assert_eq!(floyd(0, step, |n| *n, 100), expected);
+        assert_eq!(first_repeat(0, step, |n| *n, 100), expected);
+        assert_eq!(first_repeat(0, |n| n + 1, |n| *n, 100), None);
+        assert_eq!(brent(0, |n| n + 1, |n| *n, 100), None);
+        assert_eq!(floyd(0, |n| n + 1, |n| *n, 100), None);
+    }
+
+    #[test]
+    fn extrapolate() {
+        // (state, total) where total accumulates the states
+        let next = |s: &(u64, u64)| (step(&s.0), s.1 + step(&s.0));
+        let brute = |n: usize| (0..n).fold((0, 0), |s, _| next(&s)).1;
+        for n in [0, 2, 5, 6, 100, 1001] {
+            let total = metric_at((0, 0), n, next, |s| s.0, |s| s.1);
+            assert_eq!(total, Some(brute(n)));
+        }
+        // a failing step stops the search
+        let failing = |s: &u64| match *s < 4 {
+            true => Ok(s + 1),
+            false => Err(*s),
+        };
+        assert_eq!(try_metric_at(0, 3, failing, |s| *s, |s| *s), Ok(Some(3)));
+        assert_eq!(try_metric_at(0, 9, failing, |s| *s, |s| *s), Err(4));
+    }
+
+    #[test]
+    fn overflow() {
+        let cycle = Cycle {
+            start: 1,
+            period: 2,
+        };
+        let values: [u8; 4] = [0, 1, 2, 3];
+        assert_eq!(cycle.extrapolate(&values, 255), Some(255));
+        assert_eq!(cycle.extrapolate(&values, 256), None);
+        assert_eq!(cycle.extrapolate(&values, 257), None);
+        // a metric which falls over a period doesn't fit an unsigned type
+        let cycle = Cycle {
+            start: 1,
+            period: 1,
+        };
+        assert_eq!(cycle.extrapolate(&[0u8, 10, 5], 7), None);
+        assert_eq!(cycle.extrapolate(&[0i8, 10, 5], 7), Some(-20));
+    }
+}
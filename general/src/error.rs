@@ -0,0 +1,214 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+// The error type shared by the input readers, parsers and solvers
+#[derive(Debug)]
+pub enum PuzzleError {
+    // reading the input failed
+    Io(io::Error),
+
+    // the input didn't match the expected format
+    //   line and column are 1-based, a line of 0 means the line isn't known (yet)
+    //   input is the offending input line, found is the text at line:column
+    Parse {
+        line: usize,
+        column: usize,
+        input: String,
+        expected: String,
+        found: String,
+    },
+
+    // the input is well formed but the puzzle has no answer
+    NoSolution(String),
+
+    // a puzzle parameter is missing or out of range
+    InvalidParameter(String),
+
+    // an answer differs from its known value, holds the part, input and a diff
+    WrongAnswer(String),
+
+    // the day has no solver for the part (yet)
+    NotImplemented(u8),
+}
+
+impl PuzzleError {
+    // A parse error where `found` is a subslice of the `input` line,
+    // the column is computed from the position of `found` within `input`
+    pub fn parse(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        Self::Parse {
+            line: 0,
+            column: column_of(input, found),
+            input: input.to_string(),
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+
+    // A NoSolution error for an answer (or a step to it) too large for its type
+    pub fn overflow(what: impl fmt::Display) -> Self {
+        Self::NoSolution(format!("{what} overflows"))
+    }
+
+    // Sets the (1-based) line number of a parse error which doesn't know its line yet
+    pub fn at_line(self, n: usize) -> Self {
+        match self {
+            Self::Parse {
+                line: 0,
+                column,
+                input,
+                expected,
+                found,
+            } => Self::Parse {
+                line: n,
+                column,
+                input,
+                expected,
+                found,
+            },
+            err => err,
+        }
+    }
+
+    // Moves a parse error from a slice of the input which starts on line `first_line` (1-based)
+    // to the line numbers of the whole input, an unknown line becomes `first_line`
+    pub fn offset_line(self, first_line: usize) -> Self {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                input,
+                expected,
+                found,
+            } => Self::Parse {
+                line: first_line + line.saturating_sub(1),
+                column,
+                input,
+                expected,
+                found,
+            },
+            err => err,
+        }
+    }
+}
+
+// 1-based char column of `found` within `input`, 1 if `found` isn't a subslice of `input`
+fn column_of(input: &str, found: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let p = found.as_ptr() as usize;
+    match p >= start && p <= start + input.len() {
+        true => input.get(..p - start).map_or(1, |s| s.chars().count() + 1),
+        false => 1,
+    }
+}
+
+// short type name for messages, ex. "alloc::string::String" => "String"
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// Parses `field`, a subslice of the `input` line, reporting its column on error
+pub fn parse_field<T>(input: &str, field: &str) -> Result<T, PuzzleError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    field
+        .parse::<T>()
+        .map_err(|e| PuzzleError::parse(input, field, format!("{} ({e})", type_name::<T>())))
+}
+
+// Applies a line parser to each line, tagging parse errors with their line number
+pub fn parse_lines<T, F>(lines: &[String], mut f: F) -> Result<Vec<T>, PuzzleError>
+where
+    F: FnMut(&str) -> Result<T, PuzzleError>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Parse {
+                line,
+                column,
+                input,
+                expected,
+                found,
+            } => {
+                // ex.
+                //   parse error on line 3, column 6: expected u64 (invalid digit found in string), found "abc"
+                //      |
+                //    3 | move abc from 1 to 2
+                //      |      ^
+                let gutter = match line {
+                    0 => String::new(),
+                    n => n.to_string(),
+                };
+                match line {
+                    0 => write!(f, "parse error at column {column}")?,
+                    n => write!(f, "parse error on line {n}, column {column}")?,
+                }
+                writeln!(f, ": expected {expected}, found {found:?}")?;
+                writeln!(f, "{:>w$} |", "", w = gutter.len())?;
+                writeln!(f, "{gutter} | {input}")?;
+                write!(f, "{:>w$} | {:>c$}", "", "^", w = gutter.len(), c = column)
+            }
+            Self::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Self::InvalidParameter(reason) => write!(f, "invalid parameter: {reason}"),
+            Self::WrongAnswer(diff) => write!(f, "wrong answer for {diff}"),
+            Self::NotImplemented(part) => write!(f, "part {part} isn't implemented"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PuzzleError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_column() {
+        let line = "move abc from 1 to 2";
+        let field = line.split_whitespace().nth(1).unwrap();
+        match parse_field::<u64>(line, field).map_err(|e| e.at_line(3)) {
+            Err(PuzzleError::Parse {
+                line,
+                column,
+                found,
+                ..
+            }) => assert_eq!((line, column, found.as_str()), (3, 6, "abc")),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let line = "move abc from 1 to 2";
+        let err = PuzzleError::parse(line, &line[5..8], "u64").at_line(3);
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 3, column 6: expected u64, found \"abc\"\n  |\n3 | move abc from 1 to 2\n  |      ^"
+        );
+    }
+}
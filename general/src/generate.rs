@@ -0,0 +1,168 @@
+use crate::params::{split_param, Param, Params};
+use crate::{PuzzleError, Solution};
+use std::ops::RangeInclusive;
+
+// Random puzzle inputs for scale testing, benchmarks and property tests
+//
+// A day implements Generate to emit a valid input from a seeded Rng and the
+// values of its SIZES, ex. the number of elves for day 1, and the same seed and
+// sizes always give the same input
+//   aoc gen 8 --seed 7 --size size=500 > /tmp/trees
+pub trait Generate: Solution {
+    // the size parameters set with --size name=value
+    const SIZES: &'static [Param] = &[];
+
+    // size overrides small enough for the property tests in a debug build
+    const SMALL: &'static [&'static str] = &[];
+
+    fn generate(rng: &mut Rng, sizes: &Params) -> Result<Vec<String>, PuzzleError>;
+}
+
+// The input of a day generated from a seed and "name=value" size overrides
+pub fn generate<G: Generate>(
+    seed: u64,
+    overrides: &[impl AsRef<str>],
+) -> Result<Vec<String>, PuzzleError> {
+    let sizes = Params::new(G::SIZES, overrides)?;
+    G::generate(&mut Rng::new(seed), &sizes)
+}
+
+// The parameters to solve an input generated with the size overrides, those of
+// the sizes which are also PARAMS of the day, ex. day 15's bound
+pub fn solve_params<G: Generate>(overrides: &[impl AsRef<str>]) -> Result<Params, PuzzleError> {
+    let mut solving = vec![];
+    for arg in overrides {
+        let (name, _) = split_param(arg.as_ref())?;
+        if G::PARAMS.iter().any(|p| p.name == name) {
+            solving.push(arg.as_ref());
+        }
+    }
+    Params::new(G::PARAMS, &solving)
+}
+
+// An InvalidParameter error unless the size is at least `min`
+pub fn at_least(name: &str, size: usize, min: usize) -> Result<usize, PuzzleError> {
+    match size >= min {
+        true => Ok(size),
+        false => Err(PuzzleError::InvalidParameter(format!(
+            "{name}={size}: must be at least {min}"
+        ))),
+    }
+}
+
+// A small deterministic pseudo-random generator (SplitMix64)
+//
+// It's kept here rather than taken from a crate so a seed gives the same input
+// whatever the dependency versions
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // a value in 0..n, n > 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "below(0)");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    // a value in the inclusive range
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range {lo}..={hi}");
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+        match u64::try_from(span) {
+            Ok(span) => (lo as i128 + self.below(span) as i128) as i64,
+            // the whole of i64
+            Err(_) => self.next_u64() as i64,
+        }
+    }
+
+    // a usize in the inclusive range
+    pub fn size(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range {lo}..={hi}");
+        lo + self.below((hi - lo) as u64 + 1) as usize
+    }
+
+    // an index into a slice of len > 0
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    // true one time in n
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    // Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    // a random lowercase name with a length in the range
+    pub fn name(&mut self, len: RangeInclusive<usize>) -> String {
+        (0..self.size(len))
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let xs = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+        let ys = (0..100).map(|_| b.next_u64()).collect::<Vec<_>>();
+        assert_eq!(xs, ys);
+        assert_ne!(Rng::new(43).next_u64(), xs[0]);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert!((5..=9).contains(&rng.size(5..=9)));
+            assert!(rng.index(7) < 7);
+        }
+        assert_eq!(rng.range(4..=4), 4);
+        rng.range(i64::MIN..=i64::MAX);
+        // every value in a small range turns up
+        let mut seen = [false; 6];
+        for _ in 0..200 {
+            seen[rng.size(0..=5)] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = Rng::new(9);
+        let mut items = (0..50).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,502 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, RangeInclusive, Sub, SubAssign};
+use std::str::FromStr;
+
+// A 2-d point or vector
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+// A 3-d point or vector
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// component-wise arithmetic, ex. p + Point2::new(1, 0), and scaling, ex. p * 3
+macro_rules! impl_ops {
+    ($point:ident { $($c:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+// The signed integer coordinate types which have distance metrics
+pub trait Signed:
+    Copy + Ord + From<i8> + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),+) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )+};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+impl<T: Signed> Point2<T> {
+    // taxicab distance |dx| + |dy|
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // chessboard distance max(|dx|, |dy|)
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // each component as -1, 0 or 1, a single step toward the vector direction
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // the orthogonal neighbors
+    pub fn neighbors4(&self) -> [Self; 4] {
+        Dir4::ALL.map(|d| *self + d.delta())
+    }
+
+    // the neighbors including diagonals
+    pub fn neighbors8(&self) -> [Self; 8] {
+        Dir8::ALL.map(|d| *self + d.delta())
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    // taxicab distance |dx| + |dy| + |dz|
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // chessboard distance max(|dx|, |dy|, |dz|)
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    // each component as -1, 0 or 1
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    // the 6 face-adjacent neighbors
+    pub fn neighbors6(&self) -> [Self; 6] {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .map(|(x, y, z)| *self + Self::new(T::from(x), T::from(y), T::from(z)))
+    }
+}
+
+// The error parsing a direction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+// The orthogonal directions
+//
+// Parsed from "U", "D", "L", "R" or the arrows "^", "v", "<", ">"
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    // clockwise from Up
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    // the unit vector with y increasing upward, ex. Up => (0, 1)
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir4::Up => (0, 1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, -1),
+            Dir4::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    // the (row, col) offset on a grid where rows increase downward, ex. Up => (-1, 0)
+    pub fn grid_delta(self) -> (isize, isize) {
+        let d = self.delta::<isize>();
+        (-d.y, d.x)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' => Ok(Dir4::Up),
+            'R' | '>' => Ok(Dir4::Right),
+            'D' | 'v' => Ok(Dir4::Down),
+            'L' | '<' => Ok(Dir4::Left),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c).map_err(|_| ParseDirError(s.to_string())),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+// The compass directions including diagonals
+//
+// Parsed from "N", "NE", "E", "SE", "S", "SW", "W", "NW" or a Dir4
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    // clockwise from N
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    // the unit vector with y increasing upward (north), ex. NE => (1, 1)
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir8::N => (0, 1),
+            Dir8::NE => (1, 1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, -1),
+            Dir8::S => (0, -1),
+            Dir8::SW => (-1, -1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, 1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    // the (row, col) offset on a grid where rows increase downward, ex. N => (-1, 0)
+    pub fn grid_delta(self) -> (isize, isize) {
+        let d = self.delta::<isize>();
+        (-d.y, d.x)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        match d {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(Dir8::N),
+            "NE" => Ok(Dir8::NE),
+            "E" => Ok(Dir8::E),
+            "SE" => Ok(Dir8::SE),
+            "S" => Ok(Dir8::S),
+            "SW" => Ok(Dir8::SW),
+            "W" => Ok(Dir8::W),
+            "NW" => Ok(Dir8::NW),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+// The smallest axis-aligned rectangle containing a set of points, bounds are inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox2<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+// The smallest axis-aligned box containing a set of points, bounds are inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Copy + Ord> BoundingBox2<T> {
+    // None when there are no points
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Point2<T>>,
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bbox = Self {
+            min: first,
+            max: first,
+        };
+        points.for_each(|p| bbox.include(p));
+        Some(bbox)
+    }
+
+    // grows the box to contain p
+    pub fn include(&mut self, p: &Point2<T>) {
+        self.min = Point2::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point2::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: &Point2<T>) -> bool {
+        self.x_range().contains(&p.x) && self.y_range().contains(&p.y)
+    }
+
+    pub fn x_range(&self) -> RangeInclusive<T> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn y_range(&self) -> RangeInclusive<T> {
+        self.min.y..=self.max.y
+    }
+}
+
+impl<T: Copy + Ord> BoundingBox3<T> {
+    // None when there are no points
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Point3<T>>,
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bbox = Self {
+            min: first,
+            max: first,
+        };
+        points.for_each(|p| bbox.include(p));
+        Some(bbox)
+    }
+
+    // grows the box to contain p
+    pub fn include(&mut self, p: &Point3<T>) {
+        self.min = Point3::new(
+            self.min.x.min(p.x),
+            self.min.y.min(p.y),
+            self.min.z.min(p.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(p.x),
+            self.max.y.max(p.y),
+            self.max.z.max(p.z),
+        );
+    }
+
+    pub fn contains(&self, p: &Point3<T>) -> bool {
+        self.x_range().contains(&p.x)
+            && self.y_range().contains(&p.y)
+            && self.z_range().contains(&p.z)
+    }
+
+    pub fn x_range(&self) -> RangeInclusive<T> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn y_range(&self) -> RangeInclusive<T> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn z_range(&self) -> RangeInclusive<T> {
+        self.min.z..=self.max.z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.manhattan(&Point3::default()), 6);
+        assert_eq!(
+            c.neighbors6()
+                .iter()
+                .filter(|n| n.manhattan(&c) == 1)
+                .count(),
+            6
+        );
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!("R".parse::<Dir4>(), Ok(Dir4::Right));
+        assert_eq!(Dir4::try_from('<'), Ok(Dir4::Left));
+        assert!("X".parse::<Dir4>().is_err());
+        assert_eq!(Dir4::Up.delta::<i32>(), Point2::new(0, 1));
+        assert_eq!(Dir4::Up.grid_delta(), (-1, 0));
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!("SW".parse::<Dir8>(), Ok(Dir8::SW));
+        assert_eq!("U".parse::<Dir8>(), Ok(Dir8::N));
+        let total = Dir8::ALL
+            .iter()
+            .fold(Point2::default(), |p, d| p + d.delta::<i32>());
+        assert_eq!(total, Point2::new(0, 0));
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point3::new(1, 5, -1), Point3::new(3, 2, 0)];
+        let bbox = BoundingBox3::from_points(&points).unwrap();
+        assert_eq!(bbox.min, Point3::new(1, 2, -1));
+        assert_eq!(bbox.max, Point3::new(3, 5, 0));
+        assert!(bbox.contains(&Point3::new(2, 3, 0)));
+        assert!(!bbox.contains(&Point3::new(2, 3, 1)));
+        assert_eq!(BoundingBox2::<i32>::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::cycle::Metric;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// The result of a single source (or multi-source) search
+//
+// dist holds the cost of every node reached from the nearest start and prev the node
+// it was reached from, `target` is the first goal node reached (None if there wasn't one)
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    pub dist: HashMap<N, C>,
+    pub prev: HashMap<N, N>,
+    pub target: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            target: None,
+        }
+    }
+
+    // the cost to reach the goal, None if it's unreachable
+    pub fn cost(&self) -> Option<C> {
+        self.target.as_ref().and_then(|t| self.distance(t))
+    }
+
+    // the path from a start to the goal, None if it's unreachable
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.target.as_ref().and_then(|t| self.path_to(t))
+    }
+
+    // the cost to reach a node, None if it wasn't reached
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    // the path from a start to a node (both included), None if it wasn't reached
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.prev.get(path.last()?) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Breadth-first search over unit cost edges
+//
+// The search stops when a node matching `is_goal` is dequeued, use `|_| false`
+// to explore everything reachable from the starts
+pub fn bfs<N, S, F, I, G>(starts: S, mut neighbors: F, mut is_goal: G) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut q = VecDeque::new();
+    for start in starts {
+        if !search.dist.contains_key(&start) {
+            search.dist.insert(start.clone(), 0);
+            q.push_back(start);
+        }
+    }
+
+    while let Some(node) = q.pop_front() {
+        if is_goal(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        let d = search.dist[&node] + 1;
+        for next in neighbors(&node) {
+            if !search.dist.contains_key(&next) {
+                search.dist.insert(next.clone(), d);
+                search.prev.insert(next.clone(), node.clone());
+                q.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+// a priority queue entry ordered by lowest priority first
+struct State<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// Dijkstra's shortest paths over non-negative edge costs, C::default() is the zero cost
+//
+// neighbors returns (node, edge cost) pairs, the search stops when a goal is dequeued
+pub fn dijkstra<N, C, S, F, I, G>(starts: S, neighbors: F, is_goal: G) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+// A* search, Dijkstra guided by a heuristic estimate of the remaining cost to a goal
+//
+// the heuristic must never overestimate (and be consistent) for the costs to be minimal
+pub fn astar<N, C, S, F, I, H, G>(
+    starts: S,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        search.dist.insert(start.clone(), C::default());
+        heap.push(State {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(State { cost, node, .. }) = heap.pop() {
+        // skip stale entries for nodes already reached at a lower cost
+        if search.dist.get(&node).is_some_and(|d| cost > *d) {
+            continue;
+        }
+        if is_goal(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        for (next, weight) in neighbors(&node) {
+            let c = cost + weight;
+            if search.dist.get(&next).is_none_or(|d| c < *d) {
+                search.dist.insert(next.clone(), c);
+                search.prev.insert(next.clone(), node.clone());
+                heap.push(State {
+                    priority: c + heuristic(&next),
+                    cost: c,
+                    node: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+// All-pairs shortest paths between nodes 0..n
+//
+// dist[i][j] is None when j is unreachable from i,
+// prev[i][j] is the node before j on the shortest path from i
+#[derive(Clone, Debug)]
+pub struct AllPairs<C> {
+    pub dist: Vec<Vec<Option<C>>>,
+    pub prev: Vec<Vec<Option<usize>>>,
+}
+
+impl<C: Copy> AllPairs<C> {
+    pub fn distance(&self, i: usize, j: usize) -> Option<C> {
+        self.dist
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .flatten()
+    }
+
+    // the path from i to j (both included), None if j is unreachable
+    pub fn path(&self, i: usize, j: usize) -> Option<Vec<usize>> {
+        self.distance(i, j)?;
+        let mut path = vec![j];
+        let mut node = j;
+        while node != i {
+            node = self.prev[i][node]?;
+            path.push(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Floyd-Warshall https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+//
+// edges are (from, to, cost) over the nodes 0..n, an adjacency list can be flattened into
+// edges, ex. adj.iter().enumerate().flat_map(|(i, v)| v.iter().map(move |j| (i, *j, 1)))
+// and a path whose cost overflows C is left unreachable
+pub fn floyd_warshall<C, E>(n: usize, edges: E) -> AllPairs<C>
+where
+    C: Metric + Ord + Default,
+    E: IntoIterator<Item = (usize, usize, C)>,
+{
+    let mut dist = vec![vec![None; n]; n];
+    let mut prev = vec![vec![None; n]; n];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = Some(C::default());
+    }
+    for (i, j, c) in edges {
+        if dist[i][j].is_none_or(|d| c < d) {
+            dist[i][j] = Some(c);
+            prev[i][j] = Some(i);
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(ik) = dist[i][k] else { continue };
+            for j in 0..n {
+                let Some(ikj) = dist[k][j].and_then(|kj| ik.checked_add(kj)) else {
+                    continue;
+                };
+                if dist[i][j].is_none_or(|d| ikj < d) {
+                    dist[i][j] = Some(ikj);
+                    prev[i][j] = prev[k][j];
+                }
+            }
+        }
+    }
+    AllPairs { dist, prev }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a weighted graph: 0 -1-> 1 -1-> 2 -1-> 3, 0 -5-> 3, and 4 unconnected
+    fn edges() -> Vec<(usize, usize, u32)> {
+        vec![(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 3, 5)]
+    }
+
+    fn neighbors(n: &usize) -> Vec<(usize, u32)> {
+        edges()
+            .into_iter()
+            .filter(|(i, _, _)| i == n)
+            .map(|(_, j, c)| (j, c))
+            .collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let search = bfs(
+            [0],
+            |n| neighbors(n).into_iter().map(|(j, _)| j),
+            |n| *n == 3,
+        );
+        assert_eq!(search.cost(), Some(1));
+        assert_eq!(search.path(), Some(vec![0, 3]));
+
+        let search = bfs(
+            [0],
+            |n| neighbors(n).into_iter().map(|(j, _)| j),
+            |n| *n == 4,
+        );
+        assert_eq!(search.cost(), None);
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn weighted() {
+        let search = dijkstra([0], neighbors, |n| *n == 3);
+        assert_eq!(search.cost(), Some(3));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3]));
+
+        let search = astar([0], neighbors, |n| 3 - (*n as u32).min(3), |n| *n == 3);
+        assert_eq!(search.cost(), Some(3));
+
+        let search = dijkstra([0], neighbors, |n| *n == 4);
+        assert_eq!((search.cost(), search.path()), (None, None));
+    }
+
+    #[test]
+    fn all_pairs() {
+        let paths = floyd_warshall(5, edges());
+        assert_eq!(paths.distance(0, 3), Some(3));
+        assert_eq!(paths.path(0, 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(paths.path(1, 1), Some(vec![1]));
+        assert_eq!(paths.distance(3, 0), None);
+        assert_eq!(paths.path(0, 4), None);
+
+        // 0 -> 1 -> 2 overflows so only the direct edge reaches 2
+        let paths = floyd_warshall(3, [(0, 1, u8::MAX), (1, 2, 1), (0, 2, 9)]);
+        assert_eq!(paths.path(0, 2), Some(vec![0, 2]));
+        let paths = floyd_warshall(3, [(0, 1, u8::MAX), (1, 2, 1)]);
+        assert_eq!(paths.distance(0, 2), None);
+        assert_eq!(paths.path(0, 2), None);
+    }
+}
@@ -0,0 +1,426 @@
+use crate::PuzzleError;
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+
+// (row, col) offsets of the 4 orthogonal neighbors: up, down, left, right
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// (row, col) offsets of the 8 neighbors, clockwise from up-left
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+// A rectangular 2-d grid stored in row-major order
+//
+// Cells are indexed by (row, col) with (0, 0) the top left, the first line of the input
+//   grid[(row, col)]             panics when out of bounds, like a Vec
+//   grid.get((row, col))         None when out of bounds
+//   grid.get_wrapping(row, col)  wraps around the edges
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    nrows: usize,
+    ncols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // A grid of nrows x ncols cells given in row-major order, None if the sizes don't agree
+    // (or their product overflows) or there are rows without columns
+    pub fn new(nrows: usize, ncols: usize, cells: Vec<T>) -> Option<Self> {
+        match nrows.checked_mul(ncols) == Some(cells.len()) && (ncols > 0 || nrows == 0) {
+            true => Some(Self {
+                nrows,
+                ncols,
+                cells,
+            }),
+            false => None,
+        }
+    }
+
+    // A grid with every cell set to value, None if the product of the sizes overflows
+    // or there are rows without columns
+    pub fn from_elem(nrows: usize, ncols: usize, value: T) -> Option<Self>
+    where
+        T: Clone,
+    {
+        if ncols == 0 && nrows > 0 {
+            return None;
+        }
+        Some(Self {
+            nrows,
+            ncols,
+            cells: vec![value; nrows.checked_mul(ncols)?],
+        })
+    }
+
+    // Parses a char matrix, one row per line, mapping each char to a cell
+    //
+    // A char the mapper rejects (None) is a parse error at its column reporting `expected`,
+    // the rows must all be the length of the first row, which can't be empty
+    pub fn parse_with<F>(
+        lines: &[String],
+        expected: &str,
+        mut mapper: F,
+    ) -> Result<Self, PuzzleError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let ncols = lines.first().map_or(0, |line| line.chars().count());
+        if ncols == 0 && !lines.is_empty() {
+            return Err(PuzzleError::parse(&lines[0], &lines[0], "a row of cells").at_line(1));
+        }
+        // the cells are at most the bytes of the lines, whatever the rows' lengths
+        let mut cells = Vec::with_capacity(lines.iter().map(String::len).sum());
+
+        for (i, line) in lines.iter().enumerate() {
+            let start = cells.len();
+            for (j, c) in line.char_indices() {
+                match mapper(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let found = &line[j..j + c.len_utf8()];
+                        return Err(PuzzleError::parse(line, found, expected).at_line(i + 1));
+                    }
+                }
+            }
+            if cells.len() - start != ncols {
+                let expected = format!("a row of {ncols} cells");
+                return Err(PuzzleError::parse(line, line, expected).at_line(i + 1));
+            }
+        }
+
+        Ok(Self {
+            nrows: lines.len(),
+            ncols,
+            cells,
+        })
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // bounds-checked indexing
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        match pos.0 < self.nrows && pos.1 < self.ncols {
+            true => self.cells.get(pos.0 * self.ncols + pos.1),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        match pos.0 < self.nrows && pos.1 < self.ncols {
+            true => self.cells.get_mut(pos.0 * self.ncols + pos.1),
+            false => None,
+        }
+    }
+
+    // indexing which wraps around the edges, ex. (-1, -1) is the bottom right cell
+    //
+    // panics on an empty grid
+    pub fn get_wrapping(&self, row: isize, col: isize) -> &T {
+        &self[self.wrap(row, col)]
+    }
+
+    // the position of (row, col) wrapped around the edges
+    pub fn wrap(&self, row: isize, col: isize) -> (usize, usize) {
+        (
+            row.rem_euclid(self.nrows as isize) as usize,
+            col.rem_euclid(self.ncols as isize) as usize,
+        )
+    }
+
+    // the position at a (row, col) offset from pos, None if it's outside the grid
+    pub fn offset(&self, pos: (usize, usize), delta: (isize, isize)) -> Option<(usize, usize)> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        match row < self.nrows && col < self.ncols {
+            true => Some((row, col)),
+            false => None,
+        }
+    }
+
+    // the in-bounds orthogonal neighbors of pos: up, down, left, right
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS4.iter().filter_map(move |d| self.offset(pos, *d))
+    }
+
+    // the in-bounds neighbors of pos including diagonals
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS8.iter().filter_map(move |d| self.offset(pos, *d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.ncols..(row + 1) * self.ncols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.ncols..(row + 1) * self.ncols]
+    }
+
+    // the cells of a column, top to bottom
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        assert!(col < self.ncols, "column {col} out of bounds");
+        (0..self.nrows).map(move |row| &self.cells[row * self.ncols + col])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.nrows).map(|row| self.row(row))
+    }
+
+    // appends a row at the bottom, the row is returned if it's empty or the wrong length
+    // so a grid never has rows without columns
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), Vec<T>> {
+        if self.is_empty() && self.ncols == 0 {
+            self.ncols = row.len();
+        }
+        match !row.is_empty() && row.len() == self.ncols {
+            true => {
+                self.cells.extend(row);
+                self.nrows += 1;
+                Ok(())
+            }
+            false => Err(row),
+        }
+    }
+
+    // all cells with their positions in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let ncols = self.ncols.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, v)| ((i / ncols, i % ncols), v))
+    }
+
+    // the position of the first cell (row-major order) matching the predicate
+    pub fn position<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, v)| predicate(v)).map(|(pos, _)| pos)
+    }
+
+    // a new grid with the mapper applied to each cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // the sub-grid of the given row and column ranges
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            rows.end <= self.nrows && cols.end <= self.ncols,
+            "slice out of bounds"
+        );
+        let cells = rows
+            .clone()
+            .flat_map(|row| self.row(row)[cols.clone()].iter().cloned())
+            .collect();
+        Grid {
+            nrows: rows.len(),
+            ncols: cols.len(),
+            cells,
+        }
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.ncols, self.nrows, |(row, col)| (col, row))
+    }
+
+    // rotated 90 degrees clockwise, the left column becomes the top row
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let nrows = self.nrows;
+        self.remap(self.ncols, self.nrows, |(row, col)| (nrows - 1 - col, row))
+    }
+
+    // rotated 90 degrees counterclockwise, the right column becomes the top row
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let ncols = self.ncols;
+        self.remap(self.ncols, self.nrows, |(row, col)| (col, ncols - 1 - row))
+    }
+
+    // a new nrows x ncols grid taking each cell from the source position given by f
+    fn remap<F>(&self, nrows: usize, ncols: usize, f: F) -> Grid<T>
+    where
+        T: Clone,
+        F: Fn((usize, usize)) -> (usize, usize),
+    {
+        let cells = (0..nrows)
+            .flat_map(|row| (0..ncols).map(move |col| (row, col)))
+            .map(|pos| self[f(pos)].clone())
+            .collect();
+        Grid {
+            nrows,
+            ncols,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    // Parses a char matrix, one row per line
+    pub fn parse(lines: &[String]) -> Result<Self, PuzzleError> {
+        Self::parse_with(lines, "a char", Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        match self.get(pos) {
+            Some(v) => v,
+            None => panic!(
+                "{pos:?} out of bounds for a {}x{} grid",
+                self.nrows, self.ncols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        match self.get_mut(pos) {
+            Some(v) => v,
+            None => panic!("{pos:?} out of bounds for a {nrows}x{ncols} grid"),
+        }
+    }
+}
+
+// renders the cells of each row side by side, one row per line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(&text.lines().map(String::from).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let g = grid("abc\ndef");
+        assert_eq!((g.nrows(), g.ncols()), (2, 3));
+        assert_eq!(g[(1, 2)], 'f');
+        assert_eq!(g.get((2, 0)), None);
+        assert_eq!(g.get_wrapping(-1, -1), &'f');
+        assert_eq!(g.to_string(), "abc\ndef");
+
+        let lines = vec!["12".to_string(), "3x".to_string()];
+        match Grid::parse_with(&lines, "a digit", |c| c.to_digit(10)) {
+            Err(PuzzleError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            other => panic!("unexpected {other:?}"),
+        }
+
+        // a long first row of many short ones isn't allocated for before the rows are checked
+        let mut lines = vec!["1".repeat(100_000)];
+        lines.extend(std::iter::repeat_n("1".to_string(), 100_000));
+        match Grid::parse_with(&lines, "a digit", |c| c.to_digit(10)) {
+            Err(PuzzleError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {other:?}"),
+        }
+
+        // a blank first row would make rows without columns
+        for text in ["\nab", "", "\n"] {
+            let lines = text.split('\n').map(String::from).collect::<Vec<_>>();
+            assert!(Grid::parse(&lines).is_err(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Grid::new(2, 2, vec![1, 2, 3, 4]).map(|g| g[(1, 0)]),
+            Some(3)
+        );
+        assert_eq!(Grid::new(2, 2, vec![1, 2, 3]), None);
+        // the product wraps to the length of the cells
+        assert_eq!(Grid::new(1 << (usize::BITS - 1), 2, Vec::<u8>::new()), None);
+        assert_eq!(Grid::from_elem(2, 3, 0).map(|g| g.iter().count()), Some(6));
+        assert_eq!(Grid::from_elem(1 << (usize::BITS - 1), 2, 0u8), None);
+        // rows without columns
+        assert_eq!(Grid::new(3, 0, Vec::<u8>::new()), None);
+        assert_eq!(Grid::from_elem(3, 0, 0u8), None);
+        assert!(Grid::new(0, 0, Vec::<u8>::new()).is_some());
+    }
+
+    #[test]
+    fn push_row() {
+        let mut g = Grid::new(0, 0, vec![]).unwrap();
+        assert_eq!(g.push_row(vec![]), Err(vec![]));
+        assert_eq!((g.nrows(), g.ncols()), (0, 0));
+        assert_eq!(g.push_row(vec![1, 2]), Ok(()));
+        assert_eq!(g.push_row(vec![3]), Err(vec![3]));
+        assert_eq!(g.push_row(vec![3, 4]), Ok(()));
+        assert_eq!((g.nrows(), g.ncols()), (2, 2));
+    }
+
+    #[test]
+    fn neighbors() {
+        let g = grid("abc\ndef\nghi");
+        let n4 = g.neighbors4((0, 0)).map(|p| g[p]).collect::<String>();
+        assert_eq!(n4, "db");
+        let n8 = g.neighbors8((1, 1)).map(|p| g[p]).collect::<String>();
+        assert_eq!(n8, "abcfihgd");
+    }
+
+    #[test]
+    fn transforms() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.slice(0..2, 1..3).to_string(), "bc\nef");
+        assert_eq!(g.col(1).collect::<String>(), "be");
+    }
+}
@@ -0,0 +1,177 @@
+use crate::PuzzleError;
+use clap::{Arg, ArgMatches};
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+
+// A directory holding the inputs outside the repository, ex. AOC_INPUT_DIR=~/aoc/2022
+// finds day 15's input-actual as ~/aoc/2022/day_15/input-actual
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub const EXAMPLE: &str = "input-example";
+pub const ACTUAL: &str = "input-actual";
+
+// The workspace root the current directory is in, the nearest directory up from it
+// with a Cargo.toml declaring the [workspace]
+pub fn workspace_root() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|toml| toml.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+// The places a day's inputs may be, found from where the binary is run rather than
+// where it was built so an installed binary finds them too
+//   1. $AOC_INPUT_DIR/day_NN when it's set
+//   2. the current directory when it's the day directory
+//   3. day_NN under the current directory and then under the workspace_root
+pub fn day_dirs(day: u8) -> Vec<PathBuf> {
+    let day_dir = format!("day_{day:02}");
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR).filter(|d| !d.is_empty()) {
+        return vec![Path::new(&dir).join(&day_dir)];
+    }
+    let mut dirs = vec![];
+    if env::current_dir().is_ok_and(|cwd| cwd.ends_with(&day_dir)) {
+        dirs.push(PathBuf::from("."));
+    }
+    dirs.push(Path::new(".").join(&day_dir));
+    dirs.extend(workspace_root().map(|root| root.join(&day_dir)));
+    dirs
+}
+
+// the directory of a day's inputs, ex. "./day_15"
+pub fn day_dir(day: u8) -> Option<PathBuf> {
+    day_dirs(day).into_iter().find(|dir| dir.is_dir())
+}
+
+// An input named on the command line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    // -i FILE, a path as given or else a file of the day directory
+    File(PathBuf),
+    // --example, --actual or -i FILE of several days, only ever the day's own file so
+    // one in the current directory doesn't stand in for every day's
+    Day(PathBuf),
+}
+
+impl Input {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::File(file) | Self::Day(file) => file,
+        }
+    }
+
+    // the input of each of several days, -i FILE names a file of every day directory
+    pub fn per_day(self) -> Self {
+        match self {
+            Self::File(file) => Self::Day(file),
+            day => day,
+        }
+    }
+}
+
+// Resolves an input of a day
+//   1. "-" is standard input
+//   2. a File which exists as given
+//   3. a file in one of the day_dirs, ex. "input-actual" => "day_15/input-actual"
+pub fn resolve_input(day: u8, input: &Input) -> Option<PathBuf> {
+    if let Input::File(file) = input {
+        if file.as_os_str() == "-" || file.exists() {
+            return Some(file.to_path_buf());
+        }
+    }
+    day_dirs(day)
+        .into_iter()
+        .map(|dir| dir.join(input.path()))
+        .find(|path| path.exists())
+}
+
+// The input a day reads, `input` resolved with resolve_input or standard input without one.
+// Reading an interactive terminal would just hang so that's an error instead
+pub fn find_input(day: u8, input: Option<&Input>) -> Result<PathBuf, PuzzleError> {
+    match input {
+        Some(input) => resolve_input(day, input).ok_or_else(|| {
+            let dirs = day_dirs(day)
+                .iter()
+                .map(|d| d.display().to_string())
+                .collect::<Vec<_>>();
+            PuzzleError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "{} not found in {}",
+                    input.path().display(),
+                    dirs.join(", ")
+                ),
+            ))
+        }),
+        None if io::stdin().is_terminal() => Err(PuzzleError::InvalidParameter(
+            "no input file and standard input is a terminal, use -i FILE, --example or --actual"
+                .to_string(),
+        )),
+        None => Ok(PathBuf::from("-")),
+    }
+}
+
+// the --example and --actual shorthands shared by the day binaries and the aoc runner
+pub fn input_args() -> [Arg; 2] {
+    [
+        Arg::new("example")
+            .long("example")
+            .help(format!("Read the day's {EXAMPLE}"))
+            .conflicts_with_all(["FILE", "actual"])
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("actual")
+            .long("actual")
+            .help(format!("Read the day's {ACTUAL}"))
+            .conflicts_with("FILE")
+            .action(clap::ArgAction::SetTrue),
+    ]
+}
+
+// the input named by -i FILE, --example or --actual
+pub fn input_arg(args: &ArgMatches) -> Option<Input> {
+    if args.get_flag("example") {
+        return Some(Input::Day(EXAMPLE.into()));
+    }
+    if args.get_flag("actual") {
+        return Some(Input::Day(ACTUAL.into()));
+    }
+    args.get_one::<PathBuf>("FILE").cloned().map(Input::File)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_inputs() {
+        // the tests run in the general directory beside the day directories
+        let actual = resolve_input(15, &Input::Day(ACTUAL.into())).unwrap();
+        assert!(actual.ends_with("day_15/input-actual"));
+        let stdin = Input::File(PathBuf::from("-"));
+        assert_eq!(find_input(15, Some(&stdin)).unwrap(), PathBuf::from("-"));
+        let missing = Input::File(PathBuf::from("input-missing"));
+        assert!(find_input(15, Some(&missing)).is_err());
+        assert!(day_dir(99).is_none());
+
+        // a file of the current directory is taken for -i but never for a day's own
+        // input, the Cargo.toml here isn't day 15's
+        let file = resolve_input(15, &Input::File(PathBuf::from("Cargo.toml"))).unwrap();
+        assert_eq!(file, PathBuf::from("Cargo.toml"));
+        let day = resolve_input(15, &Input::Day("Cargo.toml".into())).unwrap();
+        assert!(day.ends_with("day_15/Cargo.toml"));
+        let days = Input::File(PathBuf::from("Cargo.toml")).per_day();
+        assert!(resolve_input(15, &days)
+            .unwrap()
+            .ends_with("day_15/Cargo.toml"));
+
+        // the day directories are found from the current directory, not the build's
+        let root = workspace_root().unwrap();
+        assert!(root.join("day_15").is_dir());
+        assert!(day_dirs(15).contains(&root.join("day_15")));
+    }
+}
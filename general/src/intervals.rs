@@ -0,0 +1,284 @@
+use std::fmt;
+use std::ops::{Add, RangeInclusive, Sub};
+
+// The integer types usable as interval endpoints
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> Integer for T {}
+
+// A set of integers stored as sorted, disjoint, non-adjacent closed intervals
+//
+// ex. inserting 15..=17, 15..=20, 0..=3, 3..=13, 11..=13 gives {0..=13, 15..=20}
+// and inserting 14..=14 then merges everything into {0..=20}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // (lo, hi) with lo <= hi, sorted, and separated by at least one missing value
+    intervals: Vec<(T, T)>,
+}
+
+// true if b starts right after a ends, ex. 0..=3 and 4..=5
+fn adjacent<T: Integer>(a_hi: T, b_lo: T) -> bool {
+    b_lo > a_hi && b_lo - a_hi == T::from(1)
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    // the number of disjoint intervals
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // the intervals in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|(lo, hi)| *lo..=*hi)
+    }
+
+    // adds a range, merging it with the intervals it overlaps or touches
+    // an empty range (start > end) is ignored
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut lo, mut hi) = (*range.start(), *range.end());
+        if lo > hi {
+            return;
+        }
+
+        // intervals [i, j) overlap or touch lo..=hi
+        let i = self
+            .intervals
+            .partition_point(|(_, h)| *h < lo && !adjacent(*h, lo));
+        let j = self
+            .intervals
+            .partition_point(|(l, _)| *l <= hi || adjacent(hi, *l));
+        if i < j {
+            lo = lo.min(self.intervals[i].0);
+            hi = hi.max(self.intervals[j - 1].1);
+        }
+        self.intervals.splice(i..j, [(lo, hi)]);
+    }
+
+    // removes the values of a range
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let removed = std::iter::once(range).collect::<Self>();
+        *self = self.difference(&removed);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.containing(*value).is_some()
+    }
+
+    // true if every value of the range is in the set
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        match range.start() > range.end() {
+            true => true,
+            false => self
+                .containing(*range.start())
+                .is_some_and(|i| self.intervals[i].1 >= *range.end()),
+        }
+    }
+
+    // true if any value of the range is in the set
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        let i = self.intervals.partition_point(|(_, h)| h < range.start());
+        range.start() <= range.end()
+            && self
+                .intervals
+                .get(i)
+                .is_some_and(|(lo, _)| lo <= range.end())
+    }
+
+    // the index of the interval containing value
+    fn containing(&self, value: T) -> Option<usize> {
+        let i = self.intervals.partition_point(|(_, h)| *h < value);
+        match self.intervals.get(i) {
+            Some((lo, _)) if *lo <= value => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = self.intervals.clone();
+        ranges.extend(other.intervals.iter().copied());
+        ranges.into_iter().map(|(lo, hi)| lo..=hi).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // sweep both sorted lists, advancing whichever interval ends first
+        let mut intervals = vec![];
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(&&(a_lo, a_hi)), Some(&&(b_lo, b_hi))) = (a.peek(), b.peek()) {
+            let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+            if lo <= hi {
+                intervals.push((lo, hi));
+            }
+            match a_hi < b_hi {
+                true => a.next(),
+                false => b.next(),
+            };
+        }
+        Self { intervals }
+    }
+
+    // the values of self which aren't in other
+    pub fn difference(&self, other: &Self) -> Self {
+        match self.bounds() {
+            Some(bounds) => self.intersection(&other.complement(bounds)),
+            None => Self::new(),
+        }
+    }
+
+    // the values within bounds which aren't in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (start, end) = (*bounds.start(), *bounds.end());
+        if start > end {
+            return Self::new();
+        }
+
+        let one = T::from(1);
+        let mut intervals = vec![];
+        let mut next = Some(start);
+        for (lo, hi) in self.intervals.iter().copied() {
+            let Some(from) = next else { break };
+            if hi < from {
+                continue;
+            }
+            if lo > end {
+                break;
+            }
+            if lo > from {
+                intervals.push((from, lo - one));
+            }
+            // hi < end means hi + 1 can't overflow
+            next = match hi < end {
+                true => Some(hi + one),
+                false => None,
+            };
+        }
+        if let Some(from) = next {
+            intervals.push((from, end));
+        }
+        Self { intervals }
+    }
+
+    // the missing ranges between the first and last intervals
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|w| w[0].1 + T::from(1)..=w[1].0 - T::from(1))
+    }
+
+    // the smallest range containing the set
+    pub fn bounds(&self) -> Option<RangeInclusive<T>> {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some((lo, _)), Some((_, hi))) => Some(*lo..=*hi),
+            _ => None,
+        }
+    }
+
+    // the number of values in the set
+    pub fn total_len(&self) -> T {
+        self.intervals.iter().fold(T::from(0), |total, (lo, hi)| {
+            total + (*hi - *lo) + T::from(1)
+        })
+    }
+}
+
+// collects ranges with a sort and a single merging sweep
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .filter(|r| r.start() <= r.end())
+            .map(|r| (*r.start(), *r.end()))
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+
+        let mut intervals: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (lo, hi) in ranges {
+            match intervals.last_mut() {
+                Some(last) if lo <= last.1 || adjacent(last.1, lo) => last.1 = last.1.max(hi),
+                _ => intervals.push((lo, hi)),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.intervals.iter().map(|(lo, hi)| lo..=hi))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_and_merge() {
+        let mut s = set(&[15..=17, 15..=20, 0..=3, 3..=13, 11..=13]);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![0..=13, 15..=20]);
+        assert_eq!(s.gaps().collect::<Vec<_>>(), vec![14..=14]);
+        assert_eq!(s.total_len(), 20);
+
+        let mut inserted = IntervalSet::new();
+        for r in [15..=17, 15..=20, 0..=3, 3..=13, 11..=13] {
+            inserted.insert(r);
+        }
+        assert_eq!(inserted, s);
+
+        s.insert(14..=14);
+        assert_eq!(s, set(&[0..=20]));
+        let (start, end) = (30, 29);
+        s.insert(start..=end);
+        assert_eq!(s.len(), 1);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..=5, 10..=15]);
+        let b = set(&[3..=12]);
+        assert_eq!(a.union(&b), set(&[0..=15]));
+        assert_eq!(a.intersection(&b), set(&[3..=5, 10..=12]));
+        assert_eq!(a.difference(&b), set(&[0..=2, 13..=15]));
+        assert_eq!(b.difference(&a), set(&[6..=9]));
+        assert_eq!(a.complement(-2..=20), set(&[-2..=-1, 6..=9, 16..=20]));
+        assert_eq!(a.complement(2..=12), set(&[6..=9]));
+
+        let mut c = a.clone();
+        c.remove(4..=11);
+        assert_eq!(c, set(&[0..=3, 12..=15]));
+    }
+
+    #[test]
+    fn containment() {
+        let a = set(&[0..=5, 10..=15]);
+        assert!(a.contains(&5) && !a.contains(&6));
+        assert!(a.contains_range(&(11..=15)));
+        assert!(!a.contains_range(&(4..=10)));
+        assert!(a.overlaps(&(4..=10)) && !a.overlaps(&(6..=9)));
+
+        let full = IntervalSet::from(0..=u8::MAX);
+        assert_eq!(full.complement(0..=u8::MAX), IntervalSet::new());
+    }
+}
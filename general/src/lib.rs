@@ -3,89 +3,70 @@ use clap::{
     Command,
 };
 use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::{Path, PathBuf};
+use std::fmt;
+use std::path::PathBuf;
 use std::str::FromStr;
 
-// https://doc.rust-lang.org/stable/rust-by-example/std_misc/file/read_lines.html
-//
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-#[allow(dead_code)]
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+mod error;
+pub mod generate;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod output;
+pub mod params;
+pub mod parse;
+mod records;
+mod solution;
+mod stream;
+pub use error::{parse_field, parse_lines, PuzzleError};
+pub use params::{Param, Params};
+pub use records::{
+    paragraphs, parse_records, read_records, split_paragraphs, FromRecord, Paragraphs,
+};
+pub use solution::{
+    exit_code, input_params, read_input, run, solve, solve_file, solve_with, Answers, Part,
+    Solution, Timings, EXIT_NOT_IMPLEMENTED,
+};
+pub use stream::{
+    char_stream, open_input, parse_line_stream, stream_bytes, stream_chars, stream_data_lines,
+    stream_trimmed_data_lines, CharStream,
+};
 
 // splits and trims the input String on a separator character
 // returns a Vec of parse::<T>() over the splits
-pub fn trim_split_on<T>(text: &str, sep: char) -> Result<Vec<T>, Box<dyn std::error::Error>>
+pub fn trim_split_on<T>(text: &str, sep: char) -> Result<Vec<T>, PuzzleError>
 where
-    T: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::error::Error,
-    <T as std::str::FromStr>::Err: 'static,
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
 {
     let mut parsed_splits = vec![];
     for s in text.split(sep) {
-        parsed_splits.push(s.trim().parse::<T>()?)
+        parsed_splits.push(parse_field(text, s.trim())?)
     }
     Ok(parsed_splits)
 }
 
 // Reads the lines of a file, trims and returns them as a Vec of the supplied type
-pub fn read_trimmed_data_lines<T>(
-    filename: Option<&PathBuf>,
-) -> Result<Vec<T>, Box<dyn std::error::Error>>
+pub fn read_trimmed_data_lines<T>(filename: Option<&PathBuf>) -> Result<Vec<T>, PuzzleError>
 where
-    T: FromStr,
-    <T as FromStr>::Err: 'static,
-    <T as FromStr>::Err: std::error::Error,
+    T: FromStr + 'static,
+    <T as FromStr>::Err: fmt::Display,
 {
-    let mut values = vec![];
-    match filename {
-        Some(file) if file.as_os_str() != "-" => {
-            for line in read_lines(file)? {
-                values.push(line?.trim().parse::<T>()?);
-            }
-            Ok(values)
-        }
-        _ => {
-            // STDIN
-            for line in io::BufReader::new(io::stdin()).lines() {
-                values.push(line?.trim().parse::<T>()?);
-            }
-            Ok(values)
-        }
-    }
+    stream_trimmed_data_lines(filename).collect()
 }
 
 // Reads the lines of a file and returns them as a Vec of the supplied type
-pub fn read_data_lines<T>(filename: Option<&PathBuf>) -> Result<Vec<T>, Box<dyn std::error::Error>>
+pub fn read_data_lines<T>(filename: Option<&PathBuf>) -> Result<Vec<T>, PuzzleError>
 where
-    T: FromStr,
-    <T as FromStr>::Err: 'static,
-    <T as FromStr>::Err: std::error::Error,
+    T: FromStr + 'static,
+    <T as FromStr>::Err: fmt::Display,
 {
-    let mut values = vec![];
-    match filename {
-        Some(file) if file.as_os_str() != "-" => {
-            for line in read_lines(file)? {
-                values.push(line?.parse::<T>()?);
-            }
-            Ok(values)
-        }
-        _ => {
-            // STDIN
-            for line in io::BufReader::new(io::stdin()).lines() {
-                values.push(line?.parse::<T>()?);
-            }
-            Ok(values)
-        }
-    }
+    stream_data_lines(filename).collect()
 }
 
 // This should be called in cli apps
@@ -102,9 +83,45 @@ pub fn reset_sigpipe() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+// the --output option shared by the day binaries and the aoc runner
+pub fn output_arg() -> Arg {
+    Arg::new("output")
+        .long("output")
+        .value_name("FORMAT")
+        .help("Output format: text, json (one object per line) or tsv")
+        .value_parser(|s: &str| s.parse::<output::Format>())
+        .default_value("text")
+}
+
+// the --part option shared by the day binaries and the aoc runner
+pub fn part_arg() -> Arg {
+    Arg::new("part")
+        .long("part")
+        .value_name("PART")
+        .help("Solve only part 1 or part 2, or all parts")
+        .value_parser(|s: &str| s.parse::<Part>())
+        .default_value("all")
+}
+
+// the repeatable --param option shared by the day binaries and the aoc runner
+pub fn param_arg() -> Arg {
+    Arg::new("param")
+        .short('p')
+        .long("param")
+        .value_name("NAME=VALUE")
+        .help("Set a puzzle parameter, ex. row=10 (repeatable)")
+        .value_parser(|s: &str| params::split_param(s).map(|_| s.to_string()))
+        .action(clap::ArgAction::Append)
+}
+
 // Simple clap-4 arg parser
 pub fn get_args() -> ArgMatches {
-    let app = Command::new(crate_name!())
+    args_command().get_matches_from(env::args().collect::<Vec<String>>())
+}
+
+// the arguments of a day binary
+pub(crate) fn args_command() -> Command {
+    Command::new(crate_name!())
         .version(crate_version!())
         .about(crate_description!())
         .color(ColorChoice::Auto)
@@ -112,14 +129,60 @@ pub fn get_args() -> ArgMatches {
         .arg(
             Arg::new("FILE")
                 .short('i')
-                .help("File to read, use '-' for standard input")
+                .help("File to read, ex. input-actual beside the day, use '-' for standard input")
                 .value_parser(value_parser!(PathBuf)),
         )
+        .args(input::input_args())
         .arg(
             Arg::new("time")
-            .short('t')
-            .help("Show runtime")
-            .action(clap::ArgAction::SetTrue)
-        );
-    app.get_matches_from(env::args().collect::<Vec<String>>())
+                .short('t')
+                .help("Show runtime")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .help("Check the answers against the answers.toml beside the input file")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(part_arg())
+        .arg(output_arg())
+        .arg(param_arg())
+        .arg(
+            Arg::new("bench")
+                .long("bench")
+                .value_name("N")
+                .help("Benchmark the parse, part 1 and part 2 phases over N iterations")
+                .value_parser(value_parser!(u64).range(1..)),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .value_name("N")
+                .help("Untimed iterations before benchmarking")
+                .value_parser(value_parser!(u64))
+                .default_value("3"),
+        )
+        .arg(
+            Arg::new("baseline")
+                .long("baseline")
+                .value_name("FILE")
+                .help("Flag benchmark medians slower than those saved in FILE")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("save-baseline")
+                .long("save-baseline")
+                .value_name("FILE")
+                .help("Save the benchmark medians to FILE")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("threshold")
+                .long("threshold")
+                .value_name("PERCENT")
+                .help("How much slower than the baseline is a regression")
+                .value_parser(value_parser!(f64))
+                .default_value("10"),
+        )
 }
@@ -0,0 +1,253 @@
+use crate::Answers;
+use serde_json::{json, Value};
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+// How the results of a run are written
+//   text: "Answer Part 1 = ..." for people
+//   json: one JSON object per line (JSON Lines)
+//   tsv:  a header row then one tab separated row per run
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(format!(
+                "unknown output format {s:?}, expected text, json or tsv"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+            Self::Tsv => write!(f, "tsv"),
+        }
+    }
+}
+
+// The result of solving one input of a day
+pub struct Record<'a> {
+    pub day: u8,
+    pub title: &'a str,
+    // None for standard input
+    pub input: Option<&'a Path>,
+    pub answers: &'a Answers,
+}
+
+impl Record<'_> {
+    fn input(&self) -> String {
+        self.input
+            .map_or("-".to_string(), |p| p.display().to_string())
+    }
+}
+
+// Writes records in one of the output formats
+pub struct Emitter<W: Write> {
+    out: W,
+    format: Format,
+    // text only: a "Day NN: title" line before the answers and the timings after them
+    show_day: bool,
+    show_timings: bool,
+    records: usize,
+}
+
+const TSV_HEADER: [&str; 9] = [
+    "day", "title", "input", "part1", "part2", "parse_ns", "part1_ns", "part2_ns", "total_ns",
+];
+
+impl<W: Write> Emitter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self {
+            out,
+            format,
+            show_day: false,
+            show_timings: false,
+            records: 0,
+        }
+    }
+
+    pub fn show_day(mut self, show: bool) -> Self {
+        self.show_day = show;
+        self
+    }
+
+    pub fn show_timings(mut self, show: bool) -> Self {
+        self.show_timings = show;
+        self
+    }
+
+    pub fn emit(&mut self, record: &Record) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.text(record)?,
+            Format::Json => writeln!(self.out, "{}", json_record(record))?,
+            Format::Tsv => {
+                if self.records == 0 {
+                    writeln!(self.out, "{}", TSV_HEADER.join("\t"))?;
+                }
+                writeln!(self.out, "{}", tsv_record(record).join("\t"))?;
+            }
+        }
+        self.records += 1;
+        Ok(())
+    }
+
+    fn text(&mut self, record: &Record) -> io::Result<()> {
+        let answers = record.answers;
+        if self.show_day {
+            writeln!(self.out, "Day {:02}: {}", record.day, record.title)?;
+        }
+        for part in [1, 2] {
+            if let Some(answer) = answers.part(part) {
+                writeln!(self.out, "Answer Part {part} = {answer}")?;
+            }
+        }
+        if self.show_timings {
+            // only the parts which were run
+            let t = answers.timings;
+            let mut phases = vec![format!("parse {:?}", t.parse)];
+            for (part, time) in [(1, t.part1), (2, t.part2)] {
+                if answers.part(part).is_some() {
+                    phases.push(format!("part {part} {time:?}"));
+                }
+            }
+            writeln!(self.out, "Runtime: {:?} ({})", t.total(), phases.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+// the lines of a displayed answer without the surrounding blank lines
+fn answer_lines(answer: &str) -> Vec<&str> {
+    answer.trim_matches('\n').lines().collect()
+}
+
+// an integer answer is a JSON number, a multi-line answer (ex. day 10's CRT) an array of rows
+// and a part which wasn't run is null
+fn json_answer(answer: Option<&str>) -> Value {
+    let Some(answer) = answer else {
+        return Value::Null;
+    };
+    let lines = answer_lines(answer);
+    match answer.parse::<i64>() {
+        Ok(n) => n.into(),
+        Err(_) if lines.len() > 1 => lines.into(),
+        Err(_) => answer.into(),
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn json_record(record: &Record) -> Value {
+    let t = record.answers.timings;
+    json!({
+        "day": record.day,
+        "title": record.title,
+        "input": record.input(),
+        "part1": json_answer(record.answers.part(1)),
+        "part2": json_answer(record.answers.part(2)),
+        "timings_ns": {
+            "parse": nanos(t.parse),
+            "part1": nanos(t.part1),
+            "part2": nanos(t.part2),
+            "total": nanos(t.total()),
+        },
+    })
+}
+
+// escapes the characters which would break a TSV field, a multi-line answer is joined by \n
+fn tsv_field(field: &str) -> String {
+    answer_lines(field)
+        .join("\n")
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+fn tsv_record(record: &Record) -> Vec<String> {
+    let t = record.answers.timings;
+    vec![
+        record.day.to_string(),
+        tsv_field(record.title),
+        tsv_field(&record.input()),
+        tsv_field(record.answers.part(1).unwrap_or_default()),
+        tsv_field(record.answers.part(2).unwrap_or_default()),
+        nanos(t.parse).to_string(),
+        nanos(t.part1).to_string(),
+        nanos(t.part2).to_string(),
+        nanos(t.total()).to_string(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timings;
+
+    fn emit(format: Format, part2: Option<&str>) -> String {
+        let answers = Answers {
+            part1: Some("13140".to_string()),
+            part2: part2.map(String::from),
+            timings: Timings {
+                parse: Duration::from_nanos(5),
+                part1: Duration::from_nanos(10),
+                part2: Duration::from_nanos(20),
+            },
+        };
+        let record = Record {
+            day: 10,
+            title: "Cathode-Ray Tube",
+            input: Some(Path::new("day_10/input-example")),
+            answers: &answers,
+        };
+        let mut out = vec![];
+        let mut emitter = Emitter::new(&mut out, format);
+        emitter.emit(&record).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(
+            emit(Format::Json, Some("\n## \n#  ")),
+            concat!(
+                r#"{"day":10,"title":"Cathode-Ray Tube","input":"day_10/input-example","#,
+                r###""part1":13140,"part2":["## ","#  "],"###,
+                r#""timings_ns":{"parse":5,"part1":10,"part2":20,"total":35}}"#,
+                "\n"
+            )
+        );
+        assert_eq!(
+            emit(Format::Tsv, Some("\n## \n#  ")).lines().nth(1),
+            Some("10\tCathode-Ray Tube\tday_10/input-example\t13140\t## \\n#  \t5\t10\t20\t35")
+        );
+        assert_eq!(
+            emit(Format::Text, Some("CMZ")),
+            "Answer Part 1 = 13140\nAnswer Part 2 = CMZ\n"
+        );
+        assert_eq!(emit(Format::Text, None), "Answer Part 1 = 13140\n");
+        assert!(emit(Format::Json, None).contains(r#""part2":null"#));
+        assert_eq!("tsv".parse::<Format>(), Ok(Format::Tsv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
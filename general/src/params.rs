@@ -0,0 +1,262 @@
+use crate::PuzzleError;
+use clap::{Arg, ArgMatches};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+// A value a day's solution depends on besides its input, ex. the row day 15 counts
+// in part 1 which is 10 for the example and 2000000 for the actual input
+//
+// Days declare them in Solution::PARAMS and they're set with `--param row=10`,
+// its shorthand `--row 10` or in the `params` of an input in answers.toml
+#[derive(Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+    // parses a value as the parameter's type, the error says why it's invalid
+    validate: fn(&str) -> Result<(), String>,
+}
+
+fn validate<T>(value: &str) -> Result<(), String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    value.parse::<T>().map(|_| ()).map_err(|e| e.to_string())
+}
+
+impl Param {
+    // a parameter whose values parse as T
+    pub const fn new<T>(name: &'static str, default: &'static str, help: &'static str) -> Self
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        Self {
+            name,
+            default,
+            help,
+            validate: validate::<T>,
+        }
+    }
+
+    fn check(&self, value: &str) -> Result<(), PuzzleError> {
+        (self.validate)(value)
+            .map_err(|e| PuzzleError::InvalidParameter(format!("{}={value}: {e}", self.name)))
+    }
+}
+
+impl fmt::Debug for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Param")
+            .field("name", &self.name)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+// The validated values of a day's parameters, the defaults with any overrides
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, String>,
+}
+
+// splits a "name=value" parameter argument
+pub fn split_param(arg: &str) -> Result<(&str, &str), PuzzleError> {
+    arg.split_once('=')
+        .map(|(name, value)| (name.trim(), value.trim()))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| PuzzleError::InvalidParameter(format!("expected name=value, not {arg:?}")))
+}
+
+impl Params {
+    // the default values
+    pub fn defaults(declared: &[Param]) -> Result<Self, PuzzleError> {
+        Self::new::<&str>(declared, &[])
+    }
+
+    // The defaults overridden by "name=value" arguments, later ones win
+    // an undeclared name or a value which doesn't parse is an InvalidParameter error
+    pub fn new<A: AsRef<str>>(declared: &[Param], overrides: &[A]) -> Result<Self, PuzzleError> {
+        let mut values = BTreeMap::new();
+        for param in declared {
+            param.check(param.default)?;
+            values.insert(param.name, param.default.to_string());
+        }
+        for arg in overrides {
+            let (name, value) = split_param(arg.as_ref())?;
+            let Some(param) = declared.iter().find(|p| p.name == name) else {
+                let known = declared.iter().map(|p| p.name).collect::<Vec<_>>();
+                return Err(PuzzleError::InvalidParameter(match known.is_empty() {
+                    true => format!("{name:?}, this day takes none"),
+                    false => format!("{name:?} isn't one of {}", known.join(", ")),
+                }));
+            };
+            param.check(value)?;
+            values.insert(param.name, value.to_string());
+        }
+        Ok(Self { values })
+    }
+
+    // The value of a declared parameter as its type
+    pub fn get<T>(&self, name: &str) -> Result<T, PuzzleError>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| PuzzleError::InvalidParameter(format!("{name:?} isn't declared")))?;
+        value
+            .parse::<T>()
+            .map_err(|e| PuzzleError::InvalidParameter(format!("{name}={value}: {e}")))
+    }
+}
+
+// a --NAME VALUE option for each parameter, the shorthand of --param NAME=VALUE
+pub fn param_flags<'a>(declared: impl IntoIterator<Item = &'a Param>) -> Vec<Arg> {
+    declared
+        .into_iter()
+        .map(|p| {
+            Arg::new(p.name)
+                .long(p.name)
+                .value_name("VALUE")
+                .help(p.help)
+                .action(clap::ArgAction::Append)
+        })
+        .collect()
+}
+
+// The "name=value" overrides of --param and the param_flags in command line order,
+// so a later one wins whichever form each is in
+pub fn param_overrides<'a>(
+    args: &ArgMatches,
+    declared: impl IntoIterator<Item = &'a Param>,
+) -> Vec<String> {
+    let settings = |id: &str, name: Option<&str>| {
+        let values = args.get_many::<String>(id).into_iter().flatten();
+        let indices = args.indices_of(id).into_iter().flatten();
+        indices
+            .zip(values.map(move |v| match name {
+                Some(name) => format!("{name}={v}"),
+                None => v.clone(),
+            }))
+            .collect::<Vec<_>>()
+    };
+    let mut overrides = settings("param", None);
+    for p in declared {
+        overrides.extend(settings(p.name, Some(p.name)));
+    }
+    overrides.sort_by_key(|&(i, _)| i);
+    overrides.into_iter().map(|(_, setting)| setting).collect()
+}
+
+// The parameters of a day for --help, ex.
+//   Parameters (--param NAME=VALUE or --NAME VALUE):
+//     row=2000000     the row counted in part 1
+pub fn params_help(declared: &[Param]) -> String {
+    if declared.is_empty() {
+        return String::new();
+    }
+    let settings = declared
+        .iter()
+        .map(|p| format!("{}={}", p.name, p.default))
+        .collect::<Vec<_>>();
+    let width = settings.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut help = "Parameters (--param NAME=VALUE or --NAME VALUE):".to_string();
+    for (setting, param) in settings.iter().zip(declared) {
+        help.push_str(&format!("\n  {setting:<width$}  {}", param.help));
+    }
+    help
+}
+
+// A comma separated list parameter, ex. "1,2,3"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T> FromStr for List<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| {
+                item.trim()
+                    .parse::<T>()
+                    .map_err(|e| format!("{:?}: {e}", item.trim()))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(List)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param::new::<usize>("row", "2000000", "the row counted in part 1"),
+        Param::new::<List<u8>>("ids", "1,2,3", "the ids used in part 2"),
+    ];
+
+    #[test]
+    fn defaults_and_overrides() {
+        let params = Params::defaults(PARAMS).unwrap();
+        assert_eq!(params.get::<usize>("row").unwrap(), 2000000);
+        assert_eq!(params.get::<List<u8>>("ids").unwrap(), List(vec![1, 2, 3]));
+
+        let params = Params::new(PARAMS, &["row=10", "ids = 2", "row=12"]).unwrap();
+        assert_eq!(params.get::<usize>("row").unwrap(), 12);
+        assert_eq!(params.get::<List<u8>>("ids").unwrap(), List(vec![2]));
+        assert!(params.get::<usize>("bound").is_err());
+    }
+
+    #[test]
+    fn invalid_params() {
+        for bad in ["row=-1", "ids=1,x", "bound=20", "row", "=3"] {
+            match Params::new(PARAMS, &[bad]) {
+                Err(PuzzleError::InvalidParameter(_)) => (),
+                other => panic!("{bad}: unexpected {other:?}"),
+            }
+        }
+        assert!(Params::new(&[], &["row=10"]).is_err());
+        assert!(Params::defaults(&[]).is_ok());
+    }
+
+    #[test]
+    fn help() {
+        assert_eq!(
+            params_help(PARAMS),
+            "Parameters (--param NAME=VALUE or --NAME VALUE):\n  row=2000000  the row counted in part 1\n  ids=1,2,3    the ids used in part 2"
+        );
+        assert_eq!(params_help(&[]), "");
+    }
+
+    #[test]
+    fn flags() {
+        let command = clap::Command::new("day")
+            .arg(crate::param_arg())
+            .args(param_flags(PARAMS));
+        let overrides = |argv: &[&str]| {
+            let args = command.clone().try_get_matches_from(argv).unwrap();
+            param_overrides(&args, PARAMS)
+        };
+        assert!(overrides(&["day"]).is_empty());
+        assert_eq!(
+            overrides(&["day", "--row", "10", "-p", "row=12", "--ids", "4,5"]),
+            ["row=10", "row=12", "ids=4,5"]
+        );
+        assert_eq!(
+            overrides(&["day", "-p", "row=12", "--row=10"]),
+            ["row=12", "row=10"]
+        );
+        assert!(command
+            .try_get_matches_from(["day", "--bound", "20"])
+            .is_err());
+    }
+}
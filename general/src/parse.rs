@@ -0,0 +1,144 @@
+use crate::error::parse_field;
+use crate::PuzzleError;
+use std::fmt;
+use std::str::FromStr;
+
+// Returns every signed integer in a line, ex. "Sensor at x=2, y=-18" => [2, -18]
+//
+// a '-' is a sign when a digit follows it and no digit precedes it, so a
+// range "2-4" => [2, 4], numbers which don't fit T are errors at their column
+pub fn ints<T>(line: &str) -> Result<Vec<T>, PuzzleError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    // '-' and the digits are ASCII so byte positions are char boundaries
+    let bytes = line.as_bytes();
+    let mut nums = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if signed || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            nums.push(parse_field(line, &line[start..i])?);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(nums)
+}
+
+// the word at the start of `text` for error messages
+fn word(text: &str) -> &str {
+    &text[..text.find(char::is_whitespace).unwrap_or(text.len())]
+}
+
+// Matches a line against a template where each "{}" captures a field, returns the fields
+//   ex. scan("Sensor at x=2, y=18", "Sensor at x={}, y={}") => ["2", "18"]
+//
+// a field extends to the next occurrence of the template text which follows it, or to
+// the end of the line for a trailing "{}", so fields should be separated by some text
+pub fn scan<'a>(line: &'a str, template: &str) -> Result<Vec<&'a str>, PuzzleError> {
+    let mut literals = template.split("{}");
+    let first = literals.next().unwrap_or("");
+    let Some(mut rest) = line.strip_prefix(first) else {
+        return Err(PuzzleError::parse(line, word(line), format!("{first:?}")));
+    };
+
+    let mut fields = vec![];
+    for literal in literals {
+        let end = match literal.is_empty() {
+            true => rest.len(),
+            false => rest.find(literal).ok_or_else(|| {
+                PuzzleError::parse(line, word(rest), format!("a field followed by {literal:?}"))
+            })?,
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    match rest.is_empty() {
+        true => Ok(fields),
+        false => Err(PuzzleError::parse(line, word(rest), "the end of the line")),
+    }
+}
+
+// Scans a line with a template, parsing each "{}" field to its type, ex.
+//   let (x, y) = scan!(line, "x={}, y={}" => i64, i64)?;
+//
+// evaluates to Result<(T1, T2, ...), PuzzleError>, field errors report their column
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:expr => $($t:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        let template: &str = $template;
+        $crate::parse::scan(line, template).and_then(|fields| {
+            let expected = [$(stringify!($t)),+].len();
+            if fields.len() != expected {
+                return Err($crate::PuzzleError::InvalidParameter(format!(
+                    "the scan template {template:?} has {} fields, expected {expected}",
+                    fields.len()
+                )));
+            }
+            let mut fields = fields.into_iter();
+            Ok(($($crate::parse_field::<$t>(line, fields.next().unwrap_or(""))?,)+))
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(ints::<i64>(line).unwrap(), vec![2, -18, -2, 15]);
+        assert_eq!(ints::<u32>("2-4,6-8").unwrap(), vec![2, 4, 6, 8]);
+        assert_eq!(ints::<i32>("no numbers - here").unwrap(), Vec::<i32>::new());
+
+        match ints::<u8>("move 1 from 300 to 2") {
+            Err(PuzzleError::Parse { column, found, .. }) => {
+                assert_eq!((column, found.as_str()), (13, "300"))
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    fn column<T: fmt::Debug>(r: Result<T, PuzzleError>) -> usize {
+        match r {
+            Err(PuzzleError::Parse { column, .. }) => column,
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn templates() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        let template = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+        assert_eq!(scan(line, template).unwrap(), vec!["2", "-18", "-2", "15"]);
+        assert_eq!(
+            crate::scan!(line, template => i64, i64, i64, i64).unwrap(),
+            (2, -18, -2, 15)
+        );
+        assert_eq!(
+            scan("Valve AA has DD, BB", "Valve {} has {}").unwrap(),
+            vec!["AA", "DD, BB"]
+        );
+
+        assert_eq!(
+            column(scan("Sensor at x=2; y=3", "Sensor at x={}, y={}")),
+            13
+        );
+        assert_eq!(column(scan("Beacon at x=2", "Sensor at x={}")), 1);
+        assert_eq!(column(scan("x=2, y=3 extra", "x={}, y={} ")), 10);
+        assert_eq!(column(crate::scan!("x=2, y=a", "x={}, y={}" => u8, u8)), 8);
+        assert!(crate::scan!("x=2", "x={}" => u8, u8).is_err());
+    }
+}
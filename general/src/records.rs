@@ -0,0 +1,115 @@
+use crate::{read_data_lines, PuzzleError};
+use std::path::PathBuf;
+
+// A type which can be parsed from a record, the non-blank lines of a paragraph
+//
+// Parse errors may use line numbers relative to the record (1 = first line of the record),
+// parse_records() shifts them to line numbers of the input
+pub trait FromRecord: Sized {
+    fn from_record(lines: &[String]) -> Result<Self, PuzzleError>;
+}
+
+// An iterator over the paragraphs of the input lines
+//
+// Paragraphs are separated by one or more blank (empty or whitespace) lines,
+// leading and trailing blank lines are ignored
+// Each item is (n, lines) where n is the 1-based line number of the first line
+pub struct Paragraphs<'a> {
+    lines: &'a [String],
+    offset: usize,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = (usize, &'a [String]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_blank = |line: &String| line.trim().is_empty();
+
+        // skip separators
+        let skip = self.lines.iter().take_while(|line| is_blank(line)).count();
+        self.lines = &self.lines[skip..];
+        self.offset += skip;
+        if self.lines.is_empty() {
+            return None;
+        }
+
+        let len = self
+            .lines
+            .iter()
+            .position(is_blank)
+            .unwrap_or(self.lines.len());
+        let (paragraph, rest) = self.lines.split_at(len);
+        let first_line = self.offset + 1;
+        self.lines = rest;
+        self.offset += len;
+        Some((first_line, paragraph))
+    }
+}
+
+// Iterates over the blank-line separated paragraphs of lines
+// ex. ["1", "2", "", "", "3"] => (1, ["1", "2"]), (5, ["3"])
+pub fn paragraphs(lines: &[String]) -> Paragraphs<'_> {
+    Paragraphs { lines, offset: 0 }
+}
+
+// Splits lines on blank lines
+// ex. ["1", "2", "", "3"] => [["1", "2"], ["3"]]
+pub fn split_paragraphs(lines: &[String]) -> Vec<Vec<String>> {
+    paragraphs(lines).map(|(_, p)| p.to_vec()).collect()
+}
+
+// Parses each paragraph into a record, parse errors report the line number within the input
+pub fn parse_records<T: FromRecord>(lines: &[String]) -> Result<Vec<T>, PuzzleError> {
+    paragraphs(lines)
+        .map(|(n, p)| T::from_record(p).map_err(|e| e.offset_line(n)))
+        .collect()
+}
+
+// Reads the lines of a file (or stdin) and splits them into paragraphs
+pub fn read_records(filename: Option<&PathBuf>) -> Result<Vec<Vec<String>>, PuzzleError> {
+    Ok(split_paragraphs(&read_data_lines::<String>(filename)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn paragraph_line_numbers() {
+        let input = lines("\n1\n2\n\n  \n3\n\n");
+        let found = paragraphs(&input)
+            .map(|(n, p)| (n, p.to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (2, vec!["1".to_string(), "2".to_string()]),
+                (6, vec!["3".to_string()])
+            ]
+        );
+    }
+
+    #[test]
+    fn record_errors_use_input_lines() {
+        struct Total(u64);
+        impl FromRecord for Total {
+            fn from_record(lines: &[String]) -> Result<Self, PuzzleError> {
+                let values = crate::parse_lines(lines, |l| crate::parse_field::<u64>(l, l))?;
+                Ok(Total(values.iter().sum()))
+            }
+        }
+
+        let input = lines("1\n2\n\n3\nx");
+        match parse_records::<Total>(&input) {
+            Err(PuzzleError::Parse { line, .. }) => assert_eq!(line, 5),
+            other => panic!("unexpected {:?}", other.map(|v| v.len())),
+        }
+        let input = lines("1\n2\n\n3");
+        let totals = parse_records::<Total>(&input).unwrap();
+        assert_eq!(totals.iter().map(|t| t.0).collect::<Vec<_>>(), vec![3, 3]);
+    }
+}
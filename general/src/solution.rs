@@ -0,0 +1,388 @@
+use crate::answers::{self, Manifest};
+use crate::bench::{baseline_key, bench, write_bench, Baseline};
+use crate::input::{find_input, input_arg};
+use crate::output::{Emitter, Format, Record};
+use crate::params::{param_flags, param_overrides, params_help, Param, Params};
+use crate::{
+    args_command, open_input, parse_line_stream, read_data_lines, read_trimmed_data_lines,
+    reset_sigpipe, PuzzleError,
+};
+use clap::ArgMatches;
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+// The common shape of a puzzle solution
+//
+// Each day implements this trait on a unit struct (ex. `day_01::Day01`) which
+// lets the day binary and the `aoc` runner share the same driver code:
+//   1. read the input lines (optionally trimmed)
+//   2. `parse` the lines into the day's Input type
+//   3. run `part1` and `part2` over the parsed Input
+//
+// A day whose answers depend on more than its input (ex. the row day 15 counts)
+// declares PARAMS and overrides `parse_with` to keep their values in its Input,
+// and a day which can solve from a stream sets STREAM_INPUT and overrides
+// `parse_reader` so the day never holds the whole input
+pub trait Solution {
+    // puzzle day number, 1..=25
+    const DAY: u8;
+
+    // puzzle title, ex. "Calorie Counting"
+    const TITLE: &'static str;
+
+    // read the input with read_trimmed_data_lines() instead of read_data_lines()
+    const TRIM_LINES: bool = false;
+
+    // solve a file with parse_reader() instead of reading its lines first
+    const STREAM_INPUT: bool = false;
+
+    // the parameters set with --param name=value
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(puzzle_lines: &[String]) -> Result<Self::Input, PuzzleError>;
+
+    // parse with the values of PARAMS
+    fn parse_with(puzzle_lines: &[String], _params: &Params) -> Result<Self::Input, PuzzleError> {
+        Self::parse(puzzle_lines)
+    }
+
+    // parse straight from the input, reading its lines by default
+    fn parse_reader(reader: Box<dyn BufRead>, params: &Params) -> Result<Self::Input, PuzzleError> {
+        let puzzle_lines =
+            parse_line_stream(reader, Self::TRIM_LINES).collect::<Result<Vec<_>, _>>()?;
+        Self::parse_with(&puzzle_lines, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError>;
+}
+
+// Which parts of a puzzle to solve
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    #[default]
+    All,
+}
+
+impl Part {
+    pub fn includes(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Self::All, _) | (Self::One, 1) | (Self::Two, 2)
+        )
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            "all" => Ok(Self::All),
+            _ => Err(format!("unknown part {s:?}, expected 1, 2 or all")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+// The displayed answers from a single run of a Solution,
+// None for a part which wasn't run or isn't implemented
+#[derive(Clone, Debug)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub timings: Timings,
+}
+
+impl Answers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+// The time taken by each phase of a run, reading the input isn't included unless it's streamed,
+// and a part which wasn't run took no time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+// Reads the puzzle input the way the Solution expects it
+pub fn read_input<S: Solution>(filename: Option<&PathBuf>) -> Result<Vec<String>, PuzzleError> {
+    match S::TRIM_LINES {
+        true => read_trimmed_data_lines(filename),
+        false => read_data_lines(filename),
+    }
+}
+
+// The parameters for an input, the `params` of the input in the answers.toml beside it
+// (if there is one) overridden by the "name=value" arguments
+pub fn input_params<S: Solution>(
+    filename: Option<&PathBuf>,
+    overrides: &[String],
+) -> Result<Params, PuzzleError> {
+    let mut args = match filename {
+        Some(input) => Manifest::params_for(input)?,
+        None => vec![],
+    };
+    args.extend_from_slice(overrides);
+    Params::new(S::PARAMS, &args)
+}
+
+// Parses the puzzle lines and runs both parts with the default parameters
+pub fn solve<S: Solution>(puzzle_lines: &[String]) -> Result<Answers, PuzzleError> {
+    solve_with::<S>(puzzle_lines, &Params::defaults(S::PARAMS)?, Part::All)
+}
+
+// Parses the puzzle lines and runs the selected parts, timing each phase
+pub fn solve_with<S: Solution>(
+    puzzle_lines: &[String],
+    params: &Params,
+    part: Part,
+) -> Result<Answers, PuzzleError> {
+    let (input, parse) = timed(|| S::parse_with(puzzle_lines, params))?;
+    solve_parsed::<S>(&input, parse, part)
+}
+
+// Runs the selected parts over a parsed input, timing each one
+fn solve_parsed<S: Solution>(
+    input: &S::Input,
+    parse: Duration,
+    part: Part,
+) -> Result<Answers, PuzzleError> {
+    let (part1, part1_time) = run_part(part, 1, || S::part1(input))?;
+    let (part2, part2_time) = run_part(part, 2, || S::part2(input))?;
+    Ok(Answers {
+        part1,
+        part2,
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+// Runs a part if it's selected, returning its displayed answer and how long it took
+// a part which isn't implemented is skipped unless it's the only one selected
+pub(crate) fn run_part<T: Display>(
+    selected: Part,
+    part: u8,
+    f: impl FnOnce() -> Result<T, PuzzleError>,
+) -> Result<(Option<String>, Duration), PuzzleError> {
+    if !selected.includes(part) {
+        return Ok((None, Duration::ZERO));
+    }
+    match timed(f) {
+        Ok((answer, time)) => Ok((Some(answer.to_string()), time)),
+        Err(PuzzleError::NotImplemented(_)) if selected == Part::All => Ok((None, Duration::ZERO)),
+        Err(err) => Err(err),
+    }
+}
+
+// runs a phase, returning its result and how long it took
+pub(crate) fn timed<T>(
+    f: impl FnOnce() -> Result<T, PuzzleError>,
+) -> Result<(T, Duration), PuzzleError> {
+    let timer = Instant::now();
+    let result = f()?;
+    Ok((result, timer.elapsed()))
+}
+
+// Reads the puzzle input from a file (or stdin) and solves the selected parts,
+// `overrides` are "name=value" parameter arguments
+pub fn solve_file<S: Solution>(
+    filename: Option<&PathBuf>,
+    overrides: &[String],
+    part: Part,
+) -> Result<Answers, PuzzleError> {
+    let params = input_params::<S>(filename, overrides)?;
+    if !S::STREAM_INPUT {
+        return solve_with::<S>(&read_input::<S>(filename)?, &params, part);
+    }
+
+    // a streamed input is read as it's parsed so that's included in the parse time
+    let reader = open_input(filename)?;
+    let (input, parse) = timed(|| S::parse_reader(reader, &params))?;
+    solve_parsed::<S>(&input, parse, part)
+}
+
+// The exit status for an error, EXIT_NOT_IMPLEMENTED for a part without a solver
+// and a failure for anything else
+pub fn exit_code(err: &(dyn Error + 'static)) -> ExitCode {
+    match err.downcast_ref::<PuzzleError>() {
+        Some(PuzzleError::NotImplemented(_)) => ExitCode::from(EXIT_NOT_IMPLEMENTED),
+        _ => ExitCode::FAILURE,
+    }
+}
+
+// exit status when the selected part isn't implemented, clap uses 2 for usage errors
+pub const EXIT_NOT_IMPLEMENTED: u8 = 3;
+
+// The body of main() for a day binary, errors are reported on stderr
+pub fn run<S: Solution>() -> ExitCode {
+    match run_solution::<S>() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            exit_code(err.as_ref())
+        }
+    }
+}
+
+fn run_solution<S: Solution>() -> Result<(), Box<dyn Error>> {
+    // behave like a typical unix utility
+    reset_sigpipe()?;
+
+    // parse command line arguments, --help lists the day's parameters
+    let args = args_command()
+        .args(param_flags(S::PARAMS))
+        .after_help(params_help(S::PARAMS))
+        .get_matches_from(env::args());
+    let overrides = param_overrides(&args, S::PARAMS);
+
+    // -i FILE, --example or --actual found beside the day, otherwise standard input
+    let file = find_input(S::DAY, input_arg(&args).as_ref())?;
+
+    // find the known answers before solving so a missing manifest fails fast
+    let expected = match args.get_flag("check") {
+        true => Some(Manifest::for_input(&file)?.1),
+        false => None,
+    };
+
+    let format = args
+        .get_one::<Format>("output")
+        .copied()
+        .unwrap_or_default();
+    let part = args.get_one::<Part>("part").copied().unwrap_or_default();
+    if let Some(iterations) = args.get_one::<u64>("bench") {
+        return run_bench::<S>(&args, &file, &overrides, part, *iterations as usize, format);
+    }
+
+    // read puzzle data and solve the selected parts
+    let answers = solve_file::<S>(Some(&file), &overrides, part)?;
+
+    let mut emitter = Emitter::new(io::stdout().lock(), format).show_timings(args.get_flag("time"));
+    emitter.emit(&Record {
+        day: S::DAY,
+        title: S::TITLE,
+        input: Some(file.as_path()).filter(|f| f.as_os_str() != "-"),
+        answers: &answers,
+    })?;
+
+    if let Some(expected) = expected {
+        answers::check(&file.display().to_string(), &answers, &expected)?;
+    }
+    Ok(())
+}
+
+// --bench: times each phase over the iterations, comparing the medians with a baseline
+fn run_bench<S: Solution>(
+    args: &ArgMatches,
+    file: &PathBuf,
+    overrides: &[String],
+    part: Part,
+    iterations: usize,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let warmup = args.get_one::<u64>("warmup").copied().unwrap_or(0) as usize;
+    let threshold = args.get_one::<f64>("threshold").copied().unwrap_or(0.0);
+    let key = baseline_key(S::DAY, Some(file));
+
+    let params = input_params::<S>(Some(file), overrides)?;
+    let puzzle_lines = read_input::<S>(Some(file))?;
+    let bench = bench::<S>(&puzzle_lines, &params, part, iterations, warmup)?;
+
+    let regressions = match args.get_one::<PathBuf>("baseline") {
+        Some(path) => Baseline::load(path)?.regressions(&key, &bench, threshold),
+        None => vec![],
+    };
+    let title = format!("Day {:02}: {}", S::DAY, S::TITLE);
+    write_bench(
+        io::stdout().lock(),
+        format,
+        &title,
+        &key,
+        &bench,
+        &regressions,
+    )?;
+
+    if let Some(path) = args.get_one::<PathBuf>("save-baseline") {
+        let mut baseline = Baseline::load(path)?;
+        baseline.update(&key, &bench);
+        baseline.save(path)?;
+    }
+
+    match regressions.is_empty() {
+        true => Ok(()),
+        false => Err(Box::from(format!(
+            "{} phase(s) regressed by more than {threshold}%",
+            regressions.len()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert!(Part::All.includes(1) && Part::One.includes(1) && !Part::One.includes(2));
+
+        let answer = || Ok(42);
+        assert_eq!(
+            run_part(Part::One, 1, answer).unwrap().0.as_deref(),
+            Some("42")
+        );
+        assert_eq!(
+            run_part(Part::One, 2, answer).unwrap(),
+            (None, Duration::ZERO)
+        );
+
+        // a missing part is skipped when solving all parts but fails when it's asked for
+        let missing = || Err::<u8, _>(PuzzleError::NotImplemented(2));
+        assert_eq!(run_part(Part::All, 2, missing).unwrap().0, None);
+        assert!(matches!(
+            run_part(Part::Two, 2, missing),
+            Err(PuzzleError::NotImplemented(2))
+        ));
+    }
+}
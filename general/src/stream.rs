@@ -0,0 +1,161 @@
+use crate::{parse_field, PuzzleError};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// Opens a file for buffered reading, None or "-" is standard input
+pub fn open_input(filename: Option<&PathBuf>) -> Result<Box<dyn BufRead>, PuzzleError> {
+    match filename {
+        Some(file) if file.as_os_str() != "-" => {
+            Ok(Box::new(io::BufReader::new(File::open(file)?)))
+        }
+        // STDIN
+        _ => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+// Lazily parses each line of a reader with parse::<T>(), optionally trimmed,
+// tagging parse errors with their line number
+pub fn parse_line_stream<T, R>(
+    reader: R,
+    trim: bool,
+) -> impl Iterator<Item = Result<T, PuzzleError>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+    R: BufRead,
+{
+    reader.lines().enumerate().map(move |(i, line)| {
+        let line = line?;
+        let field = if trim { line.trim() } else { &line };
+        parse_field(&line, field).map_err(|e| e.at_line(i + 1))
+    })
+}
+
+// the reader, or the error opening it as the only item of the stream
+fn stream_input<I, F>(
+    filename: Option<&PathBuf>,
+    f: F,
+) -> impl Iterator<Item = Result<I, PuzzleError>>
+where
+    F: FnOnce(Box<dyn BufRead>) -> Box<dyn Iterator<Item = Result<I, PuzzleError>>>,
+{
+    let (stream, err) = match open_input(filename) {
+        Ok(reader) => (Some(f(reader)), None),
+        Err(e) => (None, Some(Err(e))),
+    };
+    err.into_iter().chain(stream.into_iter().flatten())
+}
+
+// Streams the lines of a file (or stdin) as the supplied type, one line in memory at a time
+pub fn stream_data_lines<T>(
+    filename: Option<&PathBuf>,
+) -> impl Iterator<Item = Result<T, PuzzleError>>
+where
+    T: FromStr + 'static,
+    <T as FromStr>::Err: fmt::Display,
+{
+    stream_input(filename, |reader| {
+        Box::new(parse_line_stream(reader, false))
+    })
+}
+
+// Streams the trimmed lines of a file (or stdin) as the supplied type
+pub fn stream_trimmed_data_lines<T>(
+    filename: Option<&PathBuf>,
+) -> impl Iterator<Item = Result<T, PuzzleError>>
+where
+    T: FromStr + 'static,
+    <T as FromStr>::Err: fmt::Display,
+{
+    stream_input(filename, |reader| Box::new(parse_line_stream(reader, true)))
+}
+
+// Streams the bytes of a file (or stdin)
+pub fn stream_bytes(filename: Option<&PathBuf>) -> impl Iterator<Item = Result<u8, PuzzleError>> {
+    stream_input(filename, |reader| {
+        Box::new(reader.bytes().map(|b| b.map_err(PuzzleError::from)))
+    })
+}
+
+// Streams the chars of a file (or stdin), the input must be UTF-8
+pub fn stream_chars(filename: Option<&PathBuf>) -> impl Iterator<Item = Result<char, PuzzleError>> {
+    stream_input(filename, |reader| Box::new(char_stream(reader)))
+}
+
+// Lazily decodes the UTF-8 chars of a reader
+pub fn char_stream<R: BufRead>(reader: R) -> CharStream<R> {
+    CharStream { reader }
+}
+
+pub struct CharStream<R> {
+    reader: R,
+}
+
+impl<R: BufRead> Iterator for CharStream<R> {
+    type Item = Result<char, PuzzleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            )
+        };
+
+        // a char is 1 to 4 bytes, take one byte at a time until they decode
+        let mut buf = [0u8; 4];
+        let mut len = 0;
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e.into())),
+            };
+            let Some(&b) = available.first() else {
+                return match len {
+                    0 => None,
+                    _ => Some(Err(invalid().into())),
+                };
+            };
+            self.reader.consume(1);
+            buf[len] = b;
+            len += 1;
+
+            match std::str::from_utf8(&buf[..len]) {
+                Ok(s) => return s.chars().next().map(Ok),
+                // incomplete sequence
+                Err(e) if e.error_len().is_none() && len < buf.len() => continue,
+                Err(_) => return Some(Err(invalid().into())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_stream_errors() {
+        let input = io::Cursor::new("1\n 2 \nx\n4\n");
+        let values = parse_line_stream::<u64, _>(input, true).collect::<Vec<_>>();
+        assert_eq!(values.len(), 4);
+        assert!(matches!(values[..2], [Ok(1), Ok(2)]));
+        assert!(matches!(values[2], Err(PuzzleError::Parse { line: 3, .. })));
+        assert!(matches!(values[3], Ok(4)));
+    }
+
+    #[test]
+    fn char_stream_decodes_utf8() {
+        let input = io::Cursor::new("aé€😀\n".as_bytes());
+        let chars = char_stream(input).collect::<Result<String, _>>().unwrap();
+        assert_eq!(chars, "aé€😀\n");
+
+        let input = io::Cursor::new(&[b'a', 0xe2, 0x82][..]);
+        let chars = char_stream(input).collect::<Vec<_>>();
+        assert!(matches!(chars[..], [Ok('a'), Err(PuzzleError::Io(_))]));
+    }
+}